@@ -0,0 +1,146 @@
+#[macro_use]
+extern crate criterion;
+extern crate aoc_common;
+extern crate day_1;
+extern crate day_2;
+extern crate day_3;
+extern crate day_4;
+extern crate day_5;
+extern crate day_6;
+extern crate day_7;
+extern crate day_8;
+extern crate day_9;
+extern crate day_10;
+extern crate day_11;
+extern crate day_12;
+extern crate day_13;
+
+use std::time::Duration;
+
+use aoc_common::Solution;
+use criterion::{black_box, Criterion};
+use day_1::Day1;
+use day_2::Day2;
+use day_3::Day3;
+use day_4::Day4;
+use day_5::Day5;
+use day_6::Day6;
+use day_7::Day7;
+use day_8::Day8;
+use day_9::Day9;
+use day_10::Day10;
+use day_11::Day11;
+use day_12::Day12;
+use day_13::Day13;
+
+/// Times parse, part 1 and part 2 of `S` for each `(label, input, config)` case.
+/// Inputs are embedded at compile time so the suite never touches the network
+/// or depends on the working directory.
+fn bench_day<S: Solution>(c: &mut Criterion, day: &str, cases: Vec<(&str, &str, S::Config)>) {
+    for (label, input_str, config) in cases {
+        let mut group = c.benchmark_group(format!("{}/{}", day, label));
+        group.sample_size(10).warm_up_time(Duration::from_millis(500));
+
+        group.bench_function("parse", |b| b.iter(|| S::parse(black_box(input_str))));
+
+        let input = S::parse(input_str).unwrap();
+        group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&input), &config)));
+        group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&input), &config)));
+
+        group.finish();
+    }
+}
+
+fn day_1(c: &mut Criterion) {
+    bench_day::<Day1>(c, "day_1", vec!(
+        ("input", include_str!("../../day_1/input/input.txt"), ()),
+        ("test_1", "+3\n+3\n+4\n-2\n-4", ())
+    ));
+}
+
+fn day_2(c: &mut Criterion) {
+    bench_day::<Day2>(c, "day_2", vec!(
+        ("input", include_str!("../../day_2/input/input.txt"), day_2::Config::default()),
+        ("test_2", include_str!("../../day_2/input/test_input_2.txt"), day_2::Config::default())
+    ));
+}
+
+fn day_3(c: &mut Criterion) {
+    bench_day::<Day3>(c, "day_3", vec!(
+        ("input", include_str!("../../day_3/input/input.txt"), ()),
+        ("test_1", include_str!("../../day_3/input/test_input_1.txt"), ())
+    ));
+}
+
+fn day_4(c: &mut Criterion) {
+    bench_day::<Day4>(c, "day_4", vec!(
+        ("input", include_str!("../../day_4/input/input.txt"), ()),
+        ("test_1", include_str!("../../day_4/input/test_input_1.txt"), ())
+    ));
+}
+
+fn day_5(c: &mut Criterion) {
+    bench_day::<Day5>(c, "day_5", vec!(
+        ("input", include_str!("../../day_5/input/input.txt"), ()),
+        ("test_1", include_str!("../../day_5/input/test_input_1.txt"), ())
+    ));
+}
+
+fn day_6(c: &mut Criterion) {
+    bench_day::<Day6>(c, "day_6", vec!(
+        ("input", include_str!("../../day_6/input/input.txt"), day_6::Config::default()),
+        ("test_1", include_str!("../../day_6/input/test_input_1.txt"), day_6::Config { max_dist: 32 })
+    ));
+}
+
+fn day_7(c: &mut Criterion) {
+    bench_day::<Day7>(c, "day_7", vec!(
+        ("input", include_str!("../../day_7/input/input.txt"), day_7::Config::default()),
+        ("test_1", include_str!("../../day_7/input/test_input_1.txt"), day_7::Config { workers: 2, base_duration: 0 })
+    ));
+}
+
+fn day_8(c: &mut Criterion) {
+    bench_day::<Day8>(c, "day_8", vec!(
+        ("input", include_str!("../../day_8/input/input.txt"), ()),
+        ("test_1", include_str!("../../day_8/input/test_input_1.txt"), ())
+    ));
+}
+
+fn day_9(c: &mut Criterion) {
+    bench_day::<Day9>(c, "day_9", vec!(
+        ("input", "", day_9::Config::default()),
+        ("test_1", "", day_9::Config { players: 10, last_marble: 1618 })
+    ));
+}
+
+fn day_10(c: &mut Criterion) {
+    bench_day::<Day10>(c, "day_10", vec!(
+        ("input", include_str!("../../day_10/input/input.txt"), ()),
+        ("test_1", include_str!("../../day_10/input/test_input_1.txt"), ())
+    ));
+}
+
+fn day_11(c: &mut Criterion) {
+    bench_day::<Day11>(c, "day_11", vec!(
+        ("input", "", day_11::Config::default()),
+        ("test_1", "", day_11::Config { serial: 18, max_size: 300 })
+    ));
+}
+
+fn day_12(c: &mut Criterion) {
+    bench_day::<Day12>(c, "day_12", vec!(
+        ("test_1", include_str!("../../day_12/input/test_input_1.txt"), ())
+    ));
+}
+
+fn day_13(c: &mut Criterion) {
+    bench_day::<Day13>(c, "day_13", vec!(
+        ("input", include_str!("../../day_13/input/input.txt"), day_13::Config::default()),
+        ("test_1", include_str!("../../day_13/input/test_input_1.txt"), day_13::Config::default()),
+        ("test_3", include_str!("../../day_13/input/test_input_3.txt"), day_13::Config::default())
+    ));
+}
+
+criterion_group!(benches, day_1, day_2, day_3, day_4, day_5, day_6, day_7, day_8, day_9, day_10, day_11, day_12, day_13);
+criterion_main!(benches);
@@ -0,0 +1,282 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use day_2::distance::Distance;
+use day_13::policy::TurnPolicy;
+
+pub const USAGE: &str = "\
+Usage: aoc run --day <N> [--part <1|2>] [--input <PATH>] [OPTIONS]
+       aoc verify [--day <N>] [--answers <PATH>]
+
+Commands:
+    run                     Solve one day and print the answers
+    verify                  Check every solver against the known answers
+                            (default: answers.toml at the repo root)
+
+Options:
+    --day <N>               Day to run
+    --part <1|2>            Part to run (default: both)
+    --input <PATH>          Puzzle input file, or - for stdin
+                            (default: $AOC_INPUT_DIR/day_<N>/input/input.txt,
+                            falling back to this repo's inputs)
+    --multiplicities <N,..> Day 2 letter counts in the checksum (default: 2,3)
+    --distance <MODE>       Day 2 ID distance: hamming, levenshtein or
+                            damerau (default: hamming)
+    --max-edits <K>         Day 2 most edits between matching IDs (default: 1)
+    --image <PATH>          Day 3 also draws the fabric's coverage to PATH,
+                            as PGM if it ends in .pgm and PPM otherwise
+    --max-dist <N>          Day 6 region distance limit (default: 10000)
+    --workers <N>           Day 7 number of workers, 1 to 26 (default: 5)
+    --base-duration <N>     Day 7 base step duration (default: 60)
+    --players <N>           Day 9 number of players, at least 1 (default: 464)
+    --last-marble <N>       Day 9 last marble worth, at most 42949672
+                            (default: 71730)
+    --serial <N>            Day 11 grid serial number (default: 3463)
+    --max-size <N>          Day 11 largest square size, 1 to 300 (default: 300)
+    --turn-policy <POLICY>  Day 13 intersection turns: cycle, straight,
+                            random:<SEED> or sequence:<L|S|R...>
+                            (default: cycle)
+    --max-ticks <N>         Day 13 ticks to run before deciding no more carts
+                            will crash (default: 100000)";
+
+#[derive(Debug)]
+pub enum Command {
+    Run(Options),
+    Verify(VerifyOptions),
+    Help
+}
+
+#[derive(Debug, Default)]
+pub struct VerifyOptions {
+    pub day: Option<u32>,
+    pub answers: Option<String>
+}
+
+#[derive(Debug)]
+pub struct Options {
+    pub day: u32,
+    pub part: Option<u32>,
+    pub input: Option<String>,
+    pub multiplicities: Vec<usize>,
+    pub distance: Distance,
+    pub max_edits: usize,
+    pub image: Option<String>,
+    pub max_dist: usize,
+    pub workers: u32,
+    pub base_duration: u32,
+    pub players: u32,
+    pub last_marble: u32,
+    pub serial: i32,
+    pub max_size: usize,
+    pub turn_policy: TurnPolicy,
+    pub max_ticks: u64
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options {
+            day: 0,
+            part: None,
+            input: None,
+            multiplicities: vec!(2, 3),
+            distance: Distance::Hamming,
+            max_edits: 1,
+            image: None,
+            max_dist: 10000,
+            workers: 5,
+            base_duration: 60,
+            players: 464,
+            last_marble: 71730,
+            serial: 3463,
+            max_size: 300,
+            turn_policy: TurnPolicy::Cycle,
+            max_ticks: day_13::MAX_TICKS
+        }
+    }
+}
+
+fn parse_value<T: FromStr>(flag: &str, value: Option<&String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("{} requires a value", flag))?;
+    value.parse::<T>().map_err(|_| format!("invalid value '{}' for {}", value, flag))
+}
+
+/// Like `parse_value`, but also rejects values the solver can't run with,
+/// such as zero workers.
+fn parse_in_range<T: FromStr + PartialOrd + fmt::Display>(flag: &str, value: Option<&String>, range: RangeInclusive<T>) -> Result<T, String> {
+    let parsed = parse_value(flag, value)?;
+    if range.contains(&parsed) {
+        Ok(parsed)
+    } else {
+        Err(format!("invalid value '{}' for {}, expected {} to {}", parsed, flag, range.start(), range.end()))
+    }
+}
+
+fn parse_list(flag: &str, value: Option<&String>) -> Result<Vec<usize>, String> {
+    let value = value.ok_or_else(|| format!("{} requires a value", flag))?;
+    value.split(',')
+        .map(|s| s.trim().parse::<usize>().map_err(|_| format!("invalid value '{}' for {}", value, flag)))
+        .collect()
+}
+
+/// Sets a single per-day option such as `--workers`. Shared by the command
+/// line and the per-answer options in the answers file.
+pub fn set_option(opts: &mut Options, flag: &str, value: Option<&String>) -> Result<(), String> {
+    match flag {
+        "--multiplicities" => opts.multiplicities = parse_list(flag, value)?,
+        "--distance" => opts.distance = parse_value(flag, value)?,
+        "--max-edits" => opts.max_edits = parse_value(flag, value)?,
+        "--image" => opts.image = Some(parse_value(flag, value)?),
+        "--max-dist" => opts.max_dist = parse_value(flag, value)?,
+        "--workers" => opts.workers = parse_in_range(flag, value, 1..=26)?,
+        "--base-duration" => opts.base_duration = parse_value(flag, value)?,
+        "--players" => opts.players = parse_in_range(flag, value, 1..=u32::MAX)?,
+        "--last-marble" => opts.last_marble = parse_in_range(flag, value, 0..=day_9::MAX_LAST_MARBLE)?,
+        "--serial" => opts.serial = parse_value(flag, value)?,
+        "--max-size" => opts.max_size = parse_in_range(flag, value, 1..=day_11::GRID_SIZE)?,
+        "--turn-policy" => opts.turn_policy = parse_value(flag, value)?,
+        "--max-ticks" => opts.max_ticks = parse_value(flag, value)?,
+        _ => return Err(format!("unknown option '{}'", flag))
+    }
+
+    Ok(())
+}
+
+fn parse_run(args: &[String]) -> Result<Command, String> {
+    let mut opts = Options::default();
+    let mut day = None;
+    let mut iter = args.iter();
+
+    while let Some(flag) = iter.next() {
+        match flag.as_str() {
+            "--day" => day = Some(parse_value(flag, iter.next())?),
+            "--part" => opts.part = Some(parse_value(flag, iter.next())?),
+            "--input" => opts.input = Some(parse_value(flag, iter.next())?),
+            "--help" | "-h" => return Ok(Command::Help),
+            _ => set_option(&mut opts, flag, iter.next())?
+        }
+    }
+
+    opts.day = day.ok_or_else(|| "--day is required".to_string())?;
+
+    match opts.part {
+        None | Some(1) | Some(2) => Ok(Command::Run(opts)),
+        Some(p) => Err(format!("invalid part {}, expected 1 or 2", p))
+    }
+}
+
+fn parse_verify(args: &[String]) -> Result<Command, String> {
+    let mut opts = VerifyOptions::default();
+    let mut iter = args.iter();
+
+    while let Some(flag) = iter.next() {
+        match flag.as_str() {
+            "--day" => opts.day = Some(parse_value(flag, iter.next())?),
+            "--answers" => opts.answers = Some(parse_value(flag, iter.next())?),
+            "--help" | "-h" => return Ok(Command::Help),
+            _ => return Err(format!("unknown option '{}'", flag))
+        }
+    }
+
+    Ok(Command::Verify(opts))
+}
+
+pub fn parse(args: &[String]) -> Result<Command, String> {
+    match args.first().map(|s| s.as_str()) {
+        Some("run") => parse_run(&args[1..]),
+        Some("verify") => parse_verify(&args[1..]),
+        Some("help") | Some("--help") | Some("-h") | None => Ok(Command::Help),
+        Some(other) => Err(format!("unknown command '{}'", other))
+    }
+}
+
+#[cfg(test)]
+fn to_args(s: &str) -> Vec<String> {
+    s.split_whitespace().map(|a| a.to_string()).collect()
+}
+
+#[test]
+fn parse_run_test() {
+    match parse(&to_args("run --day 7 --part 2 --input in.txt --workers 2 --base-duration 0")).unwrap() {
+        Command::Run(opts) => {
+            assert_eq!(opts.day, 7);
+            assert_eq!(opts.part, Some(2));
+            assert_eq!(opts.input, Some("in.txt".to_string()));
+            assert_eq!(opts.workers, 2);
+            assert_eq!(opts.base_duration, 0);
+            assert_eq!(opts.max_dist, 10000);
+        },
+        _ => panic!("expected run command")
+    }
+}
+
+#[test]
+fn parse_verify_test() {
+    match parse(&to_args("verify --day 3 --answers a.toml")).unwrap() {
+        Command::Verify(opts) => {
+            assert_eq!(opts.day, Some(3));
+            assert_eq!(opts.answers, Some("a.toml".to_string()));
+        },
+        _ => panic!("expected verify command")
+    }
+    assert!(parse(&to_args("verify --workers 2")).is_err());
+}
+
+#[test]
+fn parse_turn_policy_test() {
+    match parse(&to_args("run --day 13 --turn-policy sequence:RL --max-ticks 500")).unwrap() {
+        Command::Run(opts) => assert_eq!((opts.turn_policy.to_string(), opts.max_ticks), ("sequence:RL".to_string(), 500)),
+        _ => panic!("expected run command")
+    }
+    assert!(parse(&to_args("run --day 13 --turn-policy zigzag")).is_err());
+}
+
+#[test]
+fn parse_list_test() {
+    match parse(&to_args("run --day 2 --multiplicities 2,3,4")).unwrap() {
+        Command::Run(opts) => assert_eq!(opts.multiplicities, vec!(2, 3, 4)),
+        _ => panic!("expected run command")
+    }
+    assert!(parse(&to_args("run --day 2 --multiplicities 2,x")).is_err());
+
+    match parse(&to_args("run --day 2 --distance damerau --max-edits 2")).unwrap() {
+        Command::Run(opts) => assert_eq!((opts.distance, opts.max_edits), (Distance::Damerau, 2)),
+        _ => panic!("expected run command")
+    }
+    assert!(parse(&to_args("run --day 2 --distance euclid")).is_err());
+}
+
+#[test]
+fn parse_image_test() {
+    match parse(&to_args("run --day 3 --image fabric.pgm")).unwrap() {
+        Command::Run(opts) => assert_eq!(opts.image, Some("fabric.pgm".to_string())),
+        _ => panic!("expected run command")
+    }
+    assert!(parse(&to_args("run --day 3 --image")).is_err());
+}
+
+#[test]
+fn parse_range_test() {
+    match parse(&to_args("run --day 9 --players 1 --last-marble 42949672")).unwrap() {
+        Command::Run(opts) => assert_eq!((opts.players, opts.last_marble), (1, 42949672)),
+        _ => panic!("expected run command")
+    }
+
+    assert_eq!(parse(&to_args("run --day 9 --players 0")).unwrap_err(), "invalid value '0' for --players, expected 1 to 4294967295");
+    assert!(parse(&to_args("run --day 9 --last-marble 42949673")).is_err());
+    assert!(parse(&to_args("run --day 9 --players 10 --last-marble 50000000")).is_err());
+    assert_eq!(parse(&to_args("run --day 7 --workers 0")).unwrap_err(), "invalid value '0' for --workers, expected 1 to 26");
+    assert!(parse(&to_args("run --day 7 --workers 27")).is_err());
+    assert_eq!(parse(&to_args("run --day 11 --max-size 0")).unwrap_err(), "invalid value '0' for --max-size, expected 1 to 300");
+    assert!(parse(&to_args("run --day 11 --max-size 301")).is_err());
+}
+
+#[test]
+fn parse_error_test() {
+    assert!(parse(&to_args("run --part 1")).is_err());
+    assert!(parse(&to_args("run --day 1 --part 3")).is_err());
+    assert!(parse(&to_args("run --day")).is_err());
+    assert!(parse(&to_args("run --day seven")).is_err());
+    assert!(parse(&to_args("run --day 1 --bogus")).is_err());
+    assert!(parse(&to_args("frobnicate")).is_err());
+}
@@ -0,0 +1,71 @@
+#[macro_use]
+extern crate serde_derive;
+extern crate aoc_common;
+extern crate day_1;
+extern crate day_2;
+extern crate day_3;
+extern crate day_4;
+extern crate day_5;
+extern crate day_6;
+extern crate day_7;
+extern crate day_8;
+extern crate day_9;
+extern crate day_10;
+extern crate day_11;
+extern crate day_12;
+extern crate day_13;
+extern crate toml;
+
+mod args;
+mod solve;
+mod verify;
+
+use std::env;
+use std::error::Error;
+use std::path::Path;
+use std::process;
+
+use aoc_common::input::InputSource;
+use aoc_common::solution::format_answer;
+use args::{Command, Options};
+
+fn repo_dir() -> &'static Path {
+    Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/.."))
+}
+
+fn read_input(opts: &Options) -> Result<String, Box<dyn Error>> {
+    let source = InputSource::from_arg(opts.input.as_deref(), repo_dir());
+    Ok(source.load(opts.day)?)
+}
+
+fn run(opts: &Options) -> Result<(), Box<dyn Error>> {
+    if !solve::DAYS.contains(&opts.day) {
+        return Err(format!("day {} is not implemented", opts.day).into());
+    }
+
+    let input_str = if solve::needs_input(opts.day) { read_input(opts)? } else { String::new() };
+    for (part, answer) in solve::solve(opts, &input_str)? {
+        println!("{}", format_answer(part, &answer));
+    }
+
+    Ok(())
+}
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+
+    let result = match args::parse(&args) {
+        Ok(Command::Run(opts)) => run(&opts),
+        Ok(Command::Verify(opts)) => verify::verify(&opts, repo_dir()),
+        Ok(Command::Help) => {
+            println!("{}", args::USAGE);
+            Ok(())
+        },
+        Err(e) => Err(format!("{}\n\n{}", e, args::USAGE).into())
+    };
+
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
+}
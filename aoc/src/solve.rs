@@ -0,0 +1,78 @@
+use std::error::Error;
+use std::path::Path;
+
+use aoc_common::{ParseError, Solution};
+use args::Options;
+use day_1::Day1;
+use day_2::Day2;
+use day_3::Day3;
+use day_4::Day4;
+use day_5::Day5;
+use day_6::Day6;
+use day_7::Day7;
+use day_8::Day8;
+use day_9::Day9;
+use day_10::Day10;
+use day_11::Day11;
+use day_12::Day12;
+use day_13::Day13;
+
+pub const DAYS: [u32; 13] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13];
+
+/// `(part, answer)` for each part that was asked for.
+pub type Answers = Vec<(u32, String)>;
+
+pub fn needs_input(day: u32) -> bool {
+    day != 9 && day != 11
+}
+
+fn solve_day<S: Solution>(opts: &Options, input_str: &str, config: &S::Config) -> Result<Answers, ParseError> {
+    let input = S::parse(input_str)?;
+    let mut answers = Answers::new();
+
+    if opts.part != Some(2) {
+        answers.push((1, S::part1(&input, config).to_string()));
+    }
+
+    if opts.part != Some(1) {
+        answers.push((2, S::part2(&input, config).to_string()));
+    }
+
+    Ok(answers)
+}
+
+/// Draws the day 3 fabric to the `--image` path, if one was given.
+fn write_day_3_image(opts: &Options, input_str: &str) -> Result<(), Box<dyn Error>> {
+    if let Some(ref path) = opts.image {
+        let claims = Day3::parse(input_str)?;
+        day_3::image::write_image(&claims, Path::new(path)).map_err(|e| format!("could not write {}: {}", path, e))?;
+    }
+
+    Ok(())
+}
+
+pub fn solve(opts: &Options, input_str: &str) -> Result<Answers, Box<dyn Error>> {
+    Ok(match opts.day {
+        1 => solve_day::<Day1>(opts, input_str, &())?,
+        2 => solve_day::<Day2>(opts, input_str, &day_2::Config {
+            multiplicities: opts.multiplicities.clone(),
+            distance: opts.distance,
+            max_edits: opts.max_edits
+        })?,
+        3 => {
+            write_day_3_image(opts, input_str)?;
+            solve_day::<Day3>(opts, input_str, &())?
+        },
+        4 => solve_day::<Day4>(opts, input_str, &())?,
+        5 => solve_day::<Day5>(opts, input_str, &())?,
+        6 => solve_day::<Day6>(opts, input_str, &day_6::Config { max_dist: opts.max_dist })?,
+        7 => solve_day::<Day7>(opts, input_str, &day_7::Config { workers: opts.workers, base_duration: opts.base_duration })?,
+        8 => solve_day::<Day8>(opts, input_str, &())?,
+        9 => solve_day::<Day9>(opts, input_str, &day_9::Config { players: opts.players, last_marble: opts.last_marble })?,
+        10 => solve_day::<Day10>(opts, input_str, &())?,
+        11 => solve_day::<Day11>(opts, input_str, &day_11::Config { serial: opts.serial, max_size: opts.max_size })?,
+        12 => solve_day::<Day12>(opts, input_str, &())?,
+        13 => solve_day::<Day13>(opts, input_str, &day_13::Config { policy: opts.turn_policy.clone(), max_ticks: opts.max_ticks })?,
+        day => return Err(format!("day {} is not implemented", day).into())
+    })
+}
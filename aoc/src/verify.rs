@@ -0,0 +1,165 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc_common::input::{day_input_path, InputSource};
+use args::{self, Options, VerifyOptions};
+use solve;
+use toml;
+
+/// One `[[answer]]` entry of the answers file. `input` names a file in the
+/// day's `input` directory and defaults to the real puzzle input; `options`
+/// are the same per-day flags `run` takes, without the leading `--`.
+#[derive(Debug, Deserialize)]
+struct Answer {
+    day: u32,
+    input: Option<String>,
+    #[serde(default)]
+    options: BTreeMap<String, toml::Value>,
+    part1: Option<String>,
+    part2: Option<String>
+}
+
+#[derive(Debug, Deserialize)]
+struct AnswerFile {
+    answer: Vec<Answer>
+}
+
+fn load_answers(path: &Path) -> Result<Vec<Answer>, Box<dyn Error>> {
+    let contents = fs::read_to_string(path).map_err(|e| format!("could not read {}: {}", path.display(), e))?;
+    let file: AnswerFile = toml::from_str(&contents).map_err(|e| format!("invalid answers file {}: {}", path.display(), e))?;
+    Ok(file.answer)
+}
+
+fn options_for(answer: &Answer) -> Result<Options, String> {
+    let part = match (&answer.part1, &answer.part2) {
+        (Some(_), None) => Some(1),
+        (None, Some(_)) => Some(2),
+        _ => None
+    };
+    let mut opts = Options { day: answer.day, part, ..Options::default() };
+
+    for (key, value) in &answer.options {
+        // Checking answers shouldn't leave files behind.
+        if key == "image" {
+            return Err("option 'image' writes a file, so it can't be used when verifying".to_string());
+        }
+
+        let value = match value {
+            toml::Value::String(s) => s.clone(),
+            other => other.to_string()
+        };
+        args::set_option(&mut opts, &format!("--{}", key), Some(&value))?;
+    }
+
+    Ok(opts)
+}
+
+fn input_path(repo_dir: &Path, answer: &Answer) -> Option<PathBuf> {
+    match answer.input {
+        Some(ref name) => Some(repo_dir.join(format!("day_{}", answer.day)).join("input").join(name)),
+        None if solve::needs_input(answer.day) => Some(day_input_path(repo_dir, answer.day)),
+        None => None
+    }
+}
+
+/// Solves the entry and returns a description of each part that differs
+/// from its expected answer.
+fn check(repo_dir: &Path, answer: &Answer) -> Result<Vec<String>, Box<dyn Error>> {
+    let opts = options_for(answer)?;
+    let input_str = match input_path(repo_dir, answer) {
+        Some(path) => InputSource::File(path).load(answer.day)?,
+        None => String::new()
+    };
+
+    let mismatches = solve::solve(&opts, &input_str)?.into_iter()
+        .filter_map(|(part, actual)| {
+            let expected = if part == 1 { &answer.part1 } else { &answer.part2 };
+            match expected {
+                Some(ref e) if e.trim_end() != actual.trim_end() =>
+                    Some(format!("part {}: expected {:?}, got {:?}", part, e.trim_end(), actual.trim_end())),
+                _ => None
+            }
+        })
+        .collect();
+
+    Ok(mismatches)
+}
+
+fn describe(answer: &Answer) -> String {
+    let mut desc = format!("day {}", answer.day);
+    if let Some(ref input) = answer.input {
+        desc += &format!(" {}", input);
+    }
+    for (key, value) in &answer.options {
+        desc += &format!(" --{} {}", key, value);
+    }
+    desc
+}
+
+/// Runs every entry of the answers file, optionally limited to one day,
+/// and fails if any solver no longer produces its recorded answer.
+pub fn verify(opts: &VerifyOptions, repo_dir: &Path) -> Result<(), Box<dyn Error>> {
+    let path = match opts.answers {
+        Some(ref path) => PathBuf::from(path),
+        None => repo_dir.join("answers.toml")
+    };
+
+    let answers = load_answers(&path)?;
+    let selected = answers.iter()
+        .filter(|a| opts.day.is_none_or(|d| a.day == d))
+        .collect::<Vec<_>>();
+
+    let mut failed = 0;
+    for answer in &selected {
+        match check(repo_dir, answer) {
+            Ok(ref mismatches) if mismatches.is_empty() => println!("ok       {}", describe(answer)),
+            Ok(mismatches) => {
+                failed += 1;
+                println!("MISMATCH {}", describe(answer));
+                for m in mismatches {
+                    println!("         {}", m);
+                }
+            },
+            Err(e) => {
+                failed += 1;
+                println!("ERROR    {}: {}", describe(answer), e);
+            }
+        }
+    }
+
+    println!("\n{} passed, {} failed", selected.len() - failed, failed);
+
+    match (selected.len(), failed) {
+        (0, _) => Err(format!("no answers to check in {}", path.display()).into()),
+        (_, 0) => Ok(()),
+        (_, n) => Err(format!("{} of {} answers did not match", n, selected.len()).into())
+    }
+}
+
+#[test]
+fn answers_file_test() {
+    let repo_dir = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/.."));
+    let answers = load_answers(&repo_dir.join("answers.toml")).unwrap();
+
+    assert!(!answers.is_empty());
+    for answer in &answers {
+        assert!(solve::DAYS.contains(&answer.day), "day {} is not implemented", answer.day);
+        assert!(answer.part1.is_some() || answer.part2.is_some());
+        options_for(answer).unwrap();
+        if let Some(path) = input_path(repo_dir, answer) {
+            assert!(path.is_file(), "{} does not exist", path.display());
+        }
+    }
+}
+
+#[test]
+fn options_for_test() {
+    let answer: Answer = toml::from_str("day = 7\noptions = { workers = 2 }\npart2 = \"15\"").unwrap();
+    let opts = options_for(&answer).unwrap();
+    assert_eq!((opts.workers, opts.part), (2, Some(2)));
+
+    let answer: Answer = toml::from_str("day = 3\noptions = { image = \"fabric.ppm\" }\npart1 = \"4\"").unwrap();
+    assert_eq!(options_for(&answer).unwrap_err(), "option 'image' writes a file, so it can't be used when verifying");
+}
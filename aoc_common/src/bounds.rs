@@ -0,0 +1,24 @@
+/// Smallest and largest x and y over a set of points, as `(min, max)`.
+/// Both corners are inclusive. Returns `None` for an empty set.
+pub fn bounding_box<T, I>(points: I) -> Option<((T, T), (T, T))>
+where
+    T: Ord + Copy,
+    I: IntoIterator<Item = (T, T)>,
+{
+    points.into_iter().fold(None, |bounds, (x, y)| {
+        Some(match bounds {
+            None => ((x, y), (x, y)),
+            Some(((min_x, min_y), (max_x, max_y))) => (
+                (min_x.min(x), min_y.min(y)),
+                (max_x.max(x), max_y.max(y)),
+            ),
+        })
+    })
+}
+
+#[test]
+fn bounding_box_test() {
+    assert_eq!(bounding_box(vec!((1, 6), (8, 3), (3, 4))), Some(((1, 3), (8, 6))));
+    assert_eq!(bounding_box(vec!((-2i64, 5), (4, -7))), Some(((-2, -7), (4, 5))));
+    assert_eq!(bounding_box(Vec::<(u32, u32)>::new()), None);
+}
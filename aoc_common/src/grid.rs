@@ -0,0 +1,15 @@
+use point::Point;
+
+pub type Grid = Vec<Point>;
+
+/// Every point in the half-open rectangle `[min, max)`, column by column.
+pub fn create_grid(min: Point, max: Point) -> Grid {
+    (min.0..max.0).flat_map(|x| (min.1..max.1).map(move |y| (x, y))).collect()
+}
+
+#[test]
+fn create_grid_test() {
+    assert_eq!(create_grid((0, 0), (2, 2)), vec!((0, 0), (0, 1), (1, 0), (1, 1)));
+    assert_eq!(create_grid((1, 3), (2, 5)), vec!((1, 3), (1, 4)));
+    assert!(create_grid((2, 2), (2, 5)).is_empty());
+}
@@ -0,0 +1,140 @@
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process;
+
+/// Environment variable naming a directory laid out like this repo, i.e.
+/// holding `day_<N>/input/input.txt` for each day.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    Dir(PathBuf)
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound(PathBuf),
+    Io(String, io::Error),
+    Empty(String)
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::NotFound(path) => write!(f, "input file {} does not exist", path.display()),
+            InputError::Io(source, e) => write!(f, "could not read input from {}: {}", source, e),
+            InputError::Empty(source) => write!(f, "input from {} is empty", source)
+        }
+    }
+}
+
+impl Error for InputError {}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::Dir(dir) => write!(f, "{}", dir.display())
+        }
+    }
+}
+
+impl InputSource {
+    /// `-` reads stdin and anything else is a file path. Without an argument
+    /// the directory in `AOC_INPUT_DIR` is used, falling back to `default_dir`.
+    pub fn from_arg(arg: Option<&str>, default_dir: &Path) -> InputSource {
+        match arg {
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(PathBuf::from(path)),
+            None => match env::var_os(INPUT_DIR_VAR) {
+                Some(dir) => InputSource::Dir(PathBuf::from(dir)),
+                None => InputSource::Dir(default_dir.to_path_buf())
+            }
+        }
+    }
+
+    /// Reads the puzzle input for `day`, failing if it is missing or blank.
+    pub fn load(&self, day: u32) -> Result<String, InputError> {
+        let (desc, contents) = match self {
+            InputSource::Stdin => {
+                let mut buf = String::new();
+                io::stdin().read_to_string(&mut buf).map_err(|e| InputError::Io(self.to_string(), e))?;
+                (self.to_string(), buf)
+            },
+            InputSource::File(path) => (path.display().to_string(), read_file(path)?),
+            InputSource::Dir(dir) => {
+                let path = day_input_path(dir, day);
+                (path.display().to_string(), read_file(&path)?)
+            }
+        };
+
+        if contents.trim().is_empty() {
+            Err(InputError::Empty(desc))
+        } else {
+            Ok(contents)
+        }
+    }
+}
+
+pub fn day_input_path(dir: &Path, day: u32) -> PathBuf {
+    dir.join(format!("day_{}", day)).join("input").join("input.txt")
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    if !path.is_file() {
+        return Err(InputError::NotFound(path.to_path_buf()));
+    }
+
+    fs::read_to_string(path).map_err(|e| InputError::Io(path.display().to_string(), e))
+}
+
+/// Input for a single-day binary, taken from its first command line argument.
+/// Prints the error and exits if the input can't be loaded.
+pub fn load_from_args(day: u32, default_dir: &str) -> String {
+    load_or_exit(day, env::args().nth(1).as_deref(), default_dir)
+}
+
+/// Like `load_from_args`, for binaries that take other arguments too.
+pub fn load_or_exit(day: u32, arg: Option<&str>, default_dir: &str) -> String {
+    let source = InputSource::from_arg(arg, Path::new(default_dir));
+
+    source.load(day).unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    })
+}
+
+#[test]
+fn from_arg_test() {
+    assert_eq!(InputSource::from_arg(Some("-"), Path::new(".")), InputSource::Stdin);
+    assert_eq!(InputSource::from_arg(Some("in.txt"), Path::new(".")), InputSource::File(PathBuf::from("in.txt")));
+}
+
+#[test]
+fn load_test() {
+    let dir = env::temp_dir().join(format!("aoc_common_load_test_{}", process::id()));
+    fs::create_dir_all(dir.join("day_1").join("input")).unwrap();
+    fs::create_dir_all(dir.join("day_2").join("input")).unwrap();
+    fs::write(day_input_path(&dir, 1), "+1\n-2\n").unwrap();
+    fs::write(day_input_path(&dir, 2), "\n  \n").unwrap();
+
+    assert_eq!(InputSource::Dir(dir.clone()).load(1).unwrap(), "+1\n-2\n");
+    assert_eq!(InputSource::File(day_input_path(&dir, 1)).load(1).unwrap(), "+1\n-2\n");
+    match InputSource::Dir(dir.clone()).load(2) {
+        Err(InputError::Empty(_)) => {},
+        other => panic!("expected Empty, got {:?}", other)
+    }
+    match InputSource::Dir(dir.clone()).load(3) {
+        Err(InputError::NotFound(_)) => {},
+        other => panic!("expected NotFound, got {:?}", other)
+    }
+
+    fs::remove_dir_all(dir).unwrap();
+}
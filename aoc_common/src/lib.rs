@@ -0,0 +1,14 @@
+pub mod bounds;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod point;
+pub mod rect;
+pub mod solution;
+
+pub use bounds::bounding_box;
+pub use grid::{create_grid, Grid};
+pub use parse::{parse_field, parse_lines, parse_lines_with, parse_pair, ParseError};
+pub use point::{manhattan_dist, Coord, Point};
+pub use rect::{union_area, Rect};
+pub use solution::Solution;
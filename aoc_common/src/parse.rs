@@ -0,0 +1,132 @@
+use std::any;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Where and why a puzzle input failed to parse. `line` and `column` are
+/// 1-based; a `line` of 0 means the error hasn't been placed in the input yet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: Option<usize>,
+    pub text: String,
+    pub expected: String
+}
+
+impl ParseError {
+    pub fn new(text: &str, expected: &str) -> ParseError {
+        ParseError {
+            line: 0,
+            column: None,
+            text: text.to_string(),
+            expected: expected.to_string()
+        }
+    }
+
+    /// An error for `part`, which must be a slice of `line`; the column is
+    /// taken from where `part` sits inside `line`.
+    pub fn in_line(line: &str, part: &str, expected: &str) -> ParseError {
+        ParseError {
+            column: Some(column_of(line, part)),
+            ..ParseError::new(part, expected)
+        }
+    }
+
+    pub fn at_line(mut self, line: usize) -> ParseError {
+        self.line = line;
+        self
+    }
+
+    pub fn at_column(mut self, column: usize) -> ParseError {
+        self.column = Some(column);
+        self
+    }
+
+    /// Re-anchors an error raised while parsing `part` so its column is
+    /// relative to `line`, which `part` must be a slice of.
+    pub fn within(self, line: &str, part: &str) -> ParseError {
+        let offset = column_of(line, part) - 1;
+        let column = self.column.map(|c| c + offset);
+        ParseError { column, ..self }
+    }
+}
+
+fn column_of(line: &str, part: &str) -> usize {
+    let offset = part.as_ptr() as usize - line.as_ptr() as usize;
+    assert!(offset <= line.len(), "part is not a slice of line");
+    line[..offset].chars().count() + 1
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.line > 0 {
+            write!(f, "line {}", self.line)?;
+            if let Some(c) = self.column {
+                write!(f, ", column {}", c)?;
+            }
+            write!(f, ": ")?;
+        }
+
+        write!(f, "expected {}, found {:?}", self.expected, self.text)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses a trimmed field of `line` as a `T`, reporting the field's column on failure.
+pub fn parse_field<T: FromStr>(line: &str, field: &str, expected: &str) -> Result<T, ParseError> {
+    let field = field.trim();
+    field.parse::<T>().map_err(|_| ParseError::in_line(line, field, expected))
+}
+
+/// Runs `parse` over each non-empty line of the input, tagging any error
+/// with the 1-based number of the line it came from.
+pub fn parse_lines_with<T, F>(input_str: &str, parse: F) -> Result<Vec<T>, ParseError>
+where
+    F: Fn(&str) -> Result<T, ParseError>,
+{
+    input_str.lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty())
+        .map(|(i, l)| parse(l).map_err(|e| e.at_line(i + 1)))
+        .collect()
+}
+
+/// Parses each non-empty line of the input as a `T`.
+pub fn parse_lines<T: FromStr>(input_str: &str) -> Result<Vec<T>, ParseError> {
+    parse_lines_with(input_str, |l| parse_field(l, l, any::type_name::<T>()))
+}
+
+/// Parses a `"a<sep>b"` string such as `"3, 4"` into a pair.
+pub fn parse_pair<T: FromStr>(s: &str, sep: char) -> Result<(T, T), ParseError> {
+    let expected = format!("a pair of {} separated by '{}'", any::type_name::<T>(), sep);
+    let mut split = s.splitn(2, sep);
+
+    match (split.next(), split.next()) {
+        (Some(a), Some(b)) => Ok((parse_field(s, a, &expected)?, parse_field(s, b, &expected)?)),
+        _ => Err(ParseError::in_line(s, s, &expected))
+    }
+}
+
+#[test]
+fn parse_lines_test() {
+    assert_eq!(parse_lines::<i32>("+1\n-2\n\n+3\n"), Ok(vec!(1, -2, 3)));
+
+    let err = parse_lines::<i32>("+1\n-2\n  +x3").unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (3, Some(3), "+x3"));
+    assert_eq!(err.to_string(), "line 3, column 3: expected i32, found \"+x3\"");
+}
+
+#[test]
+fn parse_pair_test() {
+    assert_eq!(parse_pair::<usize>("1, 6", ','), Ok((1, 6)));
+    assert_eq!(parse_pair::<u32>("4x4", 'x'), Ok((4, 4)));
+
+    let err = parse_pair::<u32>("4x-4", 'x').unwrap_err();
+    assert_eq!((err.column, err.text.as_str()), (Some(3), "-4"));
+    assert_eq!(parse_pair::<u32>("44", 'x').unwrap_err().column, Some(1));
+
+    let line = "size: 4x-4";
+    let err = parse_pair::<u32>(&line[6..], 'x').unwrap_err().within(line, &line[6..]);
+    assert_eq!(err.column, Some(9));
+}
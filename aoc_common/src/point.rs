@@ -0,0 +1,29 @@
+use std::fmt;
+
+pub type Point = (usize, usize);
+
+/// An `x,y` location, displayed the way the puzzles expect it as an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Coord(pub usize, pub usize);
+
+impl fmt::Display for Coord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.0, self.1)
+    }
+}
+
+pub fn manhattan_dist(a: &Point, b: &Point) -> usize {
+    a.0.abs_diff(b.0) + a.1.abs_diff(b.1)
+}
+
+#[test]
+fn manhattan_dist_test() {
+    assert_eq!(manhattan_dist(&(1, 1), &(4, 5)), 7);
+    assert_eq!(manhattan_dist(&(4, 5), &(1, 1)), 7);
+    assert_eq!(manhattan_dist(&(3, 3), &(3, 3)), 0);
+}
+
+#[test]
+fn coord_display_test() {
+    assert_eq!(Coord(7, 3).to_string(), "7,3");
+}
@@ -0,0 +1,137 @@
+use point::Point;
+
+/// The half-open rectangle of points from `p1` up to but not including
+/// `p2`: `x` in `p1.0..p2.0` and `y` in `p1.1..p2.1`. A rectangle with no
+/// width or height covers nothing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub p1: Point,
+    pub p2: Point
+}
+
+impl Rect {
+    /// The rectangle with its top left at `corner`, `size.0` wide and
+    /// `size.1` high.
+    pub fn new(corner: Point, size: Point) -> Rect {
+        Rect { p1: corner, p2: (corner.0 + size.0, corner.1 + size.1) }
+    }
+
+    pub fn width(&self) -> usize {
+        self.p2.0.saturating_sub(self.p1.0)
+    }
+
+    pub fn height(&self) -> usize {
+        self.p2.1.saturating_sub(self.p1.1)
+    }
+
+    pub fn area(&self) -> usize {
+        self.width() * self.height()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.area() == 0
+    }
+
+    pub fn contains_point(&self, p: Point) -> bool {
+        p.0 >= self.p1.0 && p.0 < self.p2.0 && p.1 >= self.p1.1 && p.1 < self.p2.1
+    }
+
+    /// Whether every point of `other` is in this rectangle. An empty
+    /// rectangle has no points, so it is inside any other.
+    pub fn contains(&self, other: &Rect) -> bool {
+        other.is_empty() || (other.p1.0 >= self.p1.0 && other.p2.0 <= self.p2.0 && other.p1.1 >= self.p1.1 && other.p2.1 <= self.p2.1)
+    }
+
+    /// The points the two rectangles share, or `None` if there are none.
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let overlap = Rect {
+            p1: (self.p1.0.max(other.p1.0), self.p1.1.max(other.p1.1)),
+            p2: (self.p2.0.min(other.p2.0), self.p2.1.min(other.p2.1))
+        };
+
+        if overlap.is_empty() { None } else { Some(overlap) }
+    }
+
+    pub fn intersects(&self, other: &Rect) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Every point in the rectangle, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = Point> {
+        let Rect { p1, p2 } = *self;
+        (p1.1..p2.1).flat_map(move |y| (p1.0..p2.0).map(move |x| (x, y)))
+    }
+}
+
+/// The number of points covered by at least one of the rectangles. The
+/// plane is cut into strips at every left and right edge, and within each
+/// strip the vertical extents of the rectangles crossing it are merged.
+pub fn union_area(rects: &[Rect]) -> usize {
+    let rects = rects.iter().filter(|r| !r.is_empty()).collect::<Vec<&Rect>>();
+    let mut xs = rects.iter().flat_map(|r| vec!(r.p1.0, r.p2.0)).collect::<Vec<usize>>();
+    xs.sort();
+    xs.dedup();
+
+    xs.windows(2).map(|strip| {
+        let mut spans = rects.iter()
+            .filter(|r| r.p1.0 <= strip[0] && r.p2.0 >= strip[1])
+            .map(|r| (r.p1.1, r.p2.1))
+            .collect::<Vec<(usize, usize)>>();
+        spans.sort();
+
+        let (covered, _) = spans.iter().fold((0, 0), |(covered, reached), &(y1, y2)| {
+            (covered + y2.saturating_sub(y1.max(reached)), reached.max(y2))
+        });
+        covered * (strip[1] - strip[0])
+    }).sum()
+}
+
+/// Every rectangle with corners inside a `size` by `size` square, empty
+/// ones included.
+#[cfg(test)]
+fn all_rects(size: usize) -> Vec<Rect> {
+    let spans = (0..=size).flat_map(|a| (a..=size).map(move |b| (a, b))).collect::<Vec<(usize, usize)>>();
+    spans.iter().flat_map(|&(x1, x2)| spans.iter().map(move |&(y1, y2)| Rect { p1: (x1, y1), p2: (x2, y2) })).collect()
+}
+
+#[test]
+fn rect_test() {
+    let r = Rect::new((1, 3), (4, 2));
+    assert_eq!(r, Rect { p1: (1, 3), p2: (5, 5) });
+    assert_eq!((r.width(), r.height(), r.area()), (4, 2, 8));
+    assert!(r.contains_point((1, 3)) && r.contains_point((4, 4)));
+    assert!(!r.contains_point((5, 4)) && !r.contains_point((4, 5)) && !r.contains_point((0, 3)));
+    assert_eq!(r.cells().take(5).collect::<Vec<Point>>(), vec!((1, 3), (2, 3), (3, 3), (4, 3), (1, 4)));
+
+    assert_eq!(r.intersection(&Rect::new((3, 1), (4, 4))), Some(Rect { p1: (3, 3), p2: (5, 5) }));
+    assert_eq!(r.intersection(&Rect::new((5, 3), (2, 2))), None);
+    assert!(Rect::new((0, 0), (0, 5)).is_empty());
+
+    assert_eq!(union_area(&[Rect::new((1, 3), (4, 4)), Rect::new((3, 1), (4, 4)), Rect::new((5, 5), (2, 2))]), 32);
+    assert_eq!(union_area(&[]), 0);
+}
+
+/// Checks every pair of rectangles in a small square against the points
+/// they cover, so each operation agrees with `contains_point`.
+#[test]
+fn rect_properties_test() {
+    let rects = all_rects(4);
+    let points = |r: &Rect| (0..5).flat_map(|y| (0..5).map(move |x| (x, y))).filter(|&p| r.contains_point(p)).collect::<Vec<Point>>();
+
+    for a in &rects {
+        let in_a = points(a);
+        assert_eq!(a.area(), in_a.len());
+        assert_eq!(a.cells().collect::<Vec<Point>>(), in_a);
+
+        for b in &rects {
+            let in_b = points(b);
+            let shared = in_a.iter().filter(|p| b.contains_point(**p)).count();
+
+            assert_eq!(a.intersects(b), shared > 0, "{:?} {:?}", a, b);
+            assert_eq!(a.intersects(b), b.intersects(a));
+            assert_eq!(a.intersection(b).map_or(0, |r| r.area()), shared);
+            assert_eq!(a.contains(b), in_b.iter().all(|&p| a.contains_point(p)), "{:?} {:?}", a, b);
+            assert_eq!(union_area(&[*a, *b]), in_a.len() + in_b.len() - shared);
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use std::fmt::Display;
+use parse::ParseError;
+
+/// A day's puzzle. The input is parsed once and both parts are answered from
+/// it. `Config` holds any puzzle parameters that aren't part of the input and
+/// defaults to the values given in the puzzle text.
+pub trait Solution {
+    type Input;
+    type Config: Default;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input, config: &Self::Config) -> Self::Part1;
+    fn part2(input: &Self::Input, config: &Self::Config) -> Self::Part2;
+
+    fn solve_part1(input_str: &str, config: &Self::Config) -> Result<Self::Part1, ParseError> {
+        Ok(Self::part1(&Self::parse(input_str)?, config))
+    }
+
+    fn solve_part2(input_str: &str, config: &Self::Config) -> Result<Self::Part2, ParseError> {
+        Ok(Self::part2(&Self::parse(input_str)?, config))
+    }
+}
+
+/// `Part N: <answer>`, with multi-line answers starting on their own line.
+pub fn format_answer(part: u32, answer: &str) -> String {
+    if answer.contains('\n') {
+        format!("Part {}:\n{}", part, answer)
+    } else {
+        format!("Part {}: {}", part, answer)
+    }
+}
+
+pub fn print_solution<S: Solution>(input_str: &str, config: &S::Config) -> Result<(), ParseError> {
+    let input = S::parse(input_str)?;
+    println!("{}", format_answer(1, &S::part1(&input, config).to_string()));
+    println!("{}", format_answer(2, &S::part2(&input, config).to_string()));
+    Ok(())
+}
+
+#[cfg(test)]
+struct Sum;
+
+#[cfg(test)]
+impl Solution for Sum {
+    type Input = Vec<i32>;
+    type Config = i32;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input_str: &str) -> Result<Vec<i32>, ParseError> {
+        ::parse::parse_lines(input_str)
+    }
+
+    fn part1(input: &Vec<i32>, _: &i32) -> i32 {
+        input.iter().sum()
+    }
+
+    fn part2(input: &Vec<i32>, scale: &i32) -> i32 {
+        input.iter().map(|x| x * scale).sum()
+    }
+}
+
+#[test]
+fn solve_test() {
+    assert_eq!(Sum::solve_part1("1\n2\n3", &0), Ok(6));
+    assert_eq!(Sum::solve_part2("1\n2\n3", &2), Ok(12));
+    assert_eq!(Sum::solve_part1("1\nx", &0).unwrap_err().line, 2);
+}
+
+#[test]
+fn format_answer_test() {
+    assert_eq!(format_answer(1, "42"), "Part 1: 42");
+    assert_eq!(format_answer(2, "#.\n.#"), "Part 2:\n#.\n.#");
+}
@@ -0,0 +1,227 @@
+extern crate aoc_common;
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::iter;
+use aoc_common::{parse_lines, ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FirstRepeat {
+    Frequency(i64),
+    NoRepeat
+}
+
+impl fmt::Display for FirstRepeat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FirstRepeat::Frequency(freq) => write!(f, "{}", freq),
+            FirstRepeat::NoRepeat => write!(f, "no repeat")
+        }
+    }
+}
+
+/// The frequency before each change of one pass, starting from 0.
+fn prefix_sums(changes: &[i32]) -> Vec<i64> {
+    iter::once(0).chain(changes.iter().scan(0, |freq, &c| {
+        *freq += i64::from(c);
+        Some(*freq)
+    })).take(changes.len()).collect()
+}
+
+/// The first frequency reached twice, with the number of changes applied
+/// when it is, counting across passes. The frequency before change `i` of
+/// pass `k` is `sums[i] + k * drift`, so a later pass can only land on
+/// `sums[j]` from a `sums[i]` congruent to it modulo the drift, and the
+/// nearest such value in the direction of drift is reached first.
+fn find_repeat(changes: &[i32]) -> Option<(u64, i64)> {
+    let n = changes.len() as u64;
+    let drift = changes.iter().map(|&c| i64::from(c)).sum::<i64>();
+    let sums = prefix_sums(changes);
+
+    let mut seen = HashSet::new();
+    if let Some((j, &freq)) = sums.iter().enumerate().find(|(_, &freq)| !seen.insert(freq)) {
+        return Some((j as u64, freq));
+    }
+    if drift == 0 {
+        return if n > 0 { Some((n, 0)) } else { None };
+    }
+
+    let mut groups: HashMap<i64, Vec<(i64, u64)>> = HashMap::new();
+    for (i, &freq) in sums.iter().enumerate() {
+        groups.entry(freq.rem_euclid(drift.abs())).or_default().push((freq * drift.signum(), i as u64));
+    }
+
+    groups.values_mut().flat_map(|group| {
+        group.sort();
+        group.windows(2).map(|w| {
+            let ((from, i), (to, _)) = (w[0], w[1]);
+            ((to - from) as u64 / drift.unsigned_abs() * n + i, to * drift.signum())
+        }).collect::<Vec<(u64, i64)>>()
+    }).min()
+}
+
+/// Where the first repeat happened: the number of full passes through the
+/// list before it, and the index of the change that produced it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Repeat {
+    pub frequency: i64,
+    pub passes: u64,
+    pub change_index: usize
+}
+
+/// The frequency's course up to its first repeat. Without a repeat, `min`
+/// and `max` cover a single pass through the list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrequencyReport {
+    pub repeat: Option<Repeat>,
+    pub min: i64,
+    pub max: i64,
+    pub drift: i64
+}
+
+impl fmt::Display for FrequencyReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.repeat {
+            Some(r) => writeln!(f, "first repeat: {} (change {} after {} full passes)", r.frequency, r.change_index, r.passes)?,
+            None => writeln!(f, "first repeat: none")?
+        }
+        writeln!(f, "range: {} to {}", self.min, self.max)?;
+        write!(f, "drift per pass: {}", self.drift)
+    }
+}
+
+pub fn analyze(changes: &[i32]) -> FrequencyReport {
+    let n = changes.len() as u64;
+    let drift = changes.iter().map(|&c| i64::from(c)).sum::<i64>();
+    let sums = prefix_sums(changes);
+    let repeat = find_repeat(changes);
+
+    if n == 0 {
+        return FrequencyReport { repeat: None, min: 0, max: 0, drift };
+    }
+
+    // `whole` full passes, then the start of the next up to the repeat; the
+    // extremes of the full passes are in either the first or the last one
+    let (whole, rest) = match repeat {
+        Some((t, _)) => (t / n, (t % n) as usize),
+        None => (1, 0)
+    };
+    let offsets = if whole > 0 { vec!(0, (whole as i64 - 1) * drift) } else { Vec::new() };
+    let pass_min = *sums.iter().min().unwrap();
+    let pass_max = *sums.iter().max().unwrap();
+
+    let reached = offsets.iter()
+        .flat_map(|o| vec!(pass_min + o, pass_max + o))
+        .chain(sums[..=rest].iter().map(|s| s + whole as i64 * drift))
+        .collect::<Vec<i64>>();
+
+    FrequencyReport {
+        repeat: repeat.map(|(t, frequency)| Repeat {
+            frequency,
+            passes: (t - 1) / n,
+            change_index: ((t - 1) % n) as usize
+        }),
+        min: *reached.iter().min().unwrap(),
+        max: *reached.iter().max().unwrap(),
+        drift
+    }
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<i32>;
+    type Config = ();
+    type Part1 = i32;
+    type Part2 = FirstRepeat;
+
+    fn parse(input_str: &str) -> Result<Vec<i32>, ParseError> {
+        parse_lines::<i32>(input_str)
+    }
+
+    fn part1(changes: &Vec<i32>, _: &()) -> i32 {
+        changes.iter().sum()
+    }
+
+    fn part2(changes: &Vec<i32>, _: &()) -> FirstRepeat {
+        find_repeat(changes).map_or(FirstRepeat::NoRepeat, |(_, freq)| FirstRepeat::Frequency(freq))
+    }
+}
+
+#[test]
+fn part_2_test() {
+    let mut test_str = "+3\n+3\n+4\n-2\n-4";
+    assert_eq!(Day1::solve_part2(test_str, &()), Ok(FirstRepeat::Frequency(10)));
+
+    test_str = "-6\n+3\n+8\n+5\n-6";
+    assert_eq!(Day1::solve_part2(test_str, &()), Ok(FirstRepeat::Frequency(5)));
+
+    test_str = "+7\n+7\n-2\n-7\n-4";
+    assert_eq!(Day1::solve_part2(test_str, &()), Ok(FirstRepeat::Frequency(14)));
+
+    test_str = "+1\n-1";
+    assert_eq!(Day1::solve_part2(test_str, &()), Ok(FirstRepeat::Frequency(0)));
+
+    test_str = "+1\n+1";
+    assert_eq!(Day1::solve_part2(test_str, &()), Ok(FirstRepeat::NoRepeat));
+}
+
+#[cfg(test)]
+fn brute_force_repeat(changes: &[i32]) -> (u64, i64) {
+    let mut seen = HashSet::new();
+    let mut freq = 0;
+    seen.insert(freq);
+
+    for (t, &c) in changes.iter().cycle().enumerate() {
+        freq += i64::from(c);
+        if !seen.insert(freq) {
+            return (t as u64 + 1, freq);
+        }
+    }
+    unreachable!()
+}
+
+#[test]
+fn find_repeat_test() {
+    let inputs = vec!(
+        vec!(3, 3, 4, -2, -4),
+        vec!(-6, 3, 8, 5, -6),
+        vec!(-3, -3, -4, 2, 4),
+        vec!(1, 2, -3),
+        vec!(10, -9, 4, -7, 1),
+        vec!(-2, 5, -4, 1, -1, 3)
+    );
+
+    for changes in inputs {
+        assert_eq!(find_repeat(&changes), Some(brute_force_repeat(&changes)), "{:?}", changes);
+    }
+}
+
+#[test]
+fn parse_error_test() {
+    let err = Day1::parse("+3\n+3\n4-\n-2").unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (3, Some(1), "4-"));
+}
+
+#[test]
+fn analyze_test() {
+    assert_eq!(analyze(&[7, 7, -2, -7, -4]), FrequencyReport {
+        repeat: Some(Repeat { frequency: 14, passes: 2, change_index: 2 }),
+        min: 0,
+        max: 16,
+        drift: 1
+    });
+
+    let report = analyze(&[1, 1]);
+    assert_eq!((report.repeat, report.min, report.max, report.drift), (None, 0, 2, 2));
+    assert_eq!(report.to_string(), "first repeat: none\nrange: 0 to 2\ndrift per pass: 2");
+
+    let changes = vec!(-6, 3, 8, 5, -6);
+    let report = analyze(&changes);
+    let (t, _) = brute_force_repeat(&changes);
+    let reached = changes.iter().cycle().take(t as usize).scan(0, |f, &c| {
+        *f += i64::from(c);
+        Some(*f)
+    }).chain(iter::once(0)).collect::<Vec<i64>>();
+    assert_eq!((report.min, report.max), (*reached.iter().min().unwrap(), *reached.iter().max().unwrap()));
+}
@@ -0,0 +1,163 @@
+extern crate aoc_common;
+extern crate regex;
+
+use std::fmt;
+use aoc_common::{bounding_box, parse_field, parse_lines_with, ParseError, Solution};
+use regex::Regex;
+
+#[derive(Debug, Clone)]
+pub struct LightPoint {
+    point : (i64, i64),
+    velocity : (i64, i64)
+}
+
+impl LightPoint {
+    fn new(line: &str) -> Result<LightPoint, ParseError> {
+        let re = Regex::new(r"position=<([\s\S\d]*?),([\s\S\d]*?)> velocity=<([\s\S\d]*?),([\s\S\d]*?)>").unwrap();
+        let caps = re.captures(line).ok_or_else(|| {
+            ParseError::in_line(line, line, "a light like 'position=< 9,  1> velocity=< 0,  2>'")
+        })?;
+        let field = |i: usize| parse_field::<i64>(line, caps.get(i).unwrap().as_str(), "an integer");
+
+        Ok(LightPoint {
+            point: (field(1)?, field(2)?),
+            velocity : (field(3)?, field(4)?)
+        })
+    }
+
+    fn tick(&self) -> LightPoint {
+        LightPoint {
+            point: (self.point.0 + self.velocity.0, self.point.1 + self.velocity.1),
+            velocity : self.velocity
+        }
+    }
+}
+
+pub type Sky = Vec<LightPoint>;
+
+/// The corners of the smallest box holding every light, or `None` for an empty sky.
+fn get_dims(sky: &[LightPoint]) -> Option<((i64, i64), (i64, i64))> {
+    bounding_box(sky.iter().map(|lp| lp.point))
+}
+
+fn get_area(sky: &[LightPoint]) -> Option<u64> {
+    let ((min_x, min_y), (max_x, max_y)) = get_dims(sky)?;
+    Some(((max_x - min_x).abs() * (max_y - min_y).abs()) as u64)
+}
+
+fn render(sky: &[LightPoint]) -> String {
+    let ((min_x, min_y), (max_x, max_y)) = get_dims(sky).unwrap_or(((0, 0), (-1, -1)));
+
+    (min_y..=max_y).map(|y| {
+        (min_x..=max_x).map(|x| {
+            if sky.iter().any(|lp| lp.point == (x, y)) { '#' } else { '.' }
+        }).collect::<String>()
+    }).collect::<Vec<String>>().join("\n")
+}
+
+/// The sky when its lights are closest together, and how many seconds it
+/// takes to get there, or `None` if there are no lights.
+fn find_message(lights: &[LightPoint]) -> Option<(Sky, u64)> {
+    let mut sky = lights.to_vec();
+    let mut area = get_area(&sky)?;
+    let mut ticks = 0;
+
+    loop {
+        let new_sky = sky.iter().map(|lp| lp.tick()).collect::<Sky>();
+        let new_area = get_area(&new_sky)?;
+        if new_area > area {
+            break;
+        } else {
+            sky = new_sky;
+            area = new_area;
+        }
+        ticks += 1;
+    }
+
+    Some((sky, ticks))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Message {
+    Text(String),
+    NoLights
+}
+
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Message::Text(text) => write!(f, "{}", text),
+            Message::NoLights => write!(f, "no lights")
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageTime {
+    Seconds(u64),
+    NoLights
+}
+
+impl fmt::Display for MessageTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MessageTime::Seconds(n) => write!(f, "{}", n),
+            MessageTime::NoLights => write!(f, "no lights")
+        }
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Sky;
+    type Config = ();
+    type Part1 = Message;
+    type Part2 = MessageTime;
+
+    fn parse(input_str: &str) -> Result<Sky, ParseError> {
+        parse_lines_with(input_str, LightPoint::new)
+    }
+
+    fn part1(sky: &Sky, _: &()) -> Message {
+        find_message(sky).map_or(Message::NoLights, |(message, _)| Message::Text(render(&message)))
+    }
+
+    fn part2(sky: &Sky, _: &()) -> MessageTime {
+        find_message(sky).map_or(MessageTime::NoLights, |(_, ticks)| MessageTime::Seconds(ticks))
+    }
+}
+
+#[test]
+fn part_1_test() {
+    assert_eq!(Day10::solve_part1(include_str!("../input/test_input_1.txt"), &()).unwrap().to_string(),
+               "#...#..###\n\
+                #...#...#.\n\
+                #...#...#.\n\
+                #####...#.\n\
+                #...#...#.\n\
+                #...#...#.\n\
+                #...#...#.\n\
+                #...#..###");
+}
+
+#[test]
+fn part_2_test() {
+    assert_eq!(Day10::solve_part2(include_str!("../input/test_input_1.txt"), &()), Ok(MessageTime::Seconds(3)));
+}
+
+#[test]
+fn no_lights_test() {
+    assert_eq!(Day10::solve_part1("", &()), Ok(Message::NoLights));
+    assert_eq!(Day10::solve_part2("\n\n", &()), Ok(MessageTime::NoLights));
+    assert_eq!(Message::NoLights.to_string(), "no lights");
+}
+
+#[test]
+fn parse_error_test() {
+    let err = Day10::parse("position=< 9,  1> velocity=< 0,  2>\nposition=< 7,  0> velocity=<-1, +x>").unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (2, Some(33), "+x"));
+
+    let err = Day10::parse("position=< 9,  1>").unwrap_err();
+    assert_eq!((err.line, err.column), (1, Some(1)));
+}
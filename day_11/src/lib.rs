@@ -0,0 +1,180 @@
+extern crate aoc_common;
+
+use std::fmt;
+use aoc_common::{create_grid, Coord, ParseError, Solution};
+
+type PowerGrid = Vec<Vec<i32>>;
+
+/// The width and height of the grid of fuel cells.
+pub const GRID_SIZE: usize = 300;
+
+fn precompute_sums(power_vals: &PowerGrid) -> PowerGrid {
+    let mut precompute = vec!(vec!(0;power_vals[0].len());power_vals.len());
+    precompute[0].copy_from_slice(&power_vals[0]);
+
+    for c in 1..power_vals.len() {
+        for r in 0..power_vals[0].len() {
+            precompute[c][r] = power_vals[c][r] + precompute[c-1][r];
+        }
+    }
+
+    for row in precompute.iter_mut() {
+        for r in 1..row.len() {
+            row[r] += row[r-1];
+        }
+    }
+
+    precompute
+}
+
+fn get_cell_power(cell: &(usize, usize), serial_num: i32) -> i32 {
+    let rack_id = cell.0 as i32 + 10;
+    let power_lvl = ((rack_id * cell.1 as i32) + serial_num) * rack_id;
+    
+    if power_lvl < 100 {
+        -5
+    } else {
+        ((power_lvl / 100) % 10) - 5
+    }
+}
+
+fn build_power_grid(size: usize, serial_num: i32) -> PowerGrid {
+    (1..=size).map(|y| (1..=size).map(|x| {
+        get_cell_power(&(x, y), serial_num)
+    }).collect::<Vec<i32>>()).collect::<PowerGrid>()
+}
+
+fn part_1_solve(serial_num: i32) -> (usize, usize) {
+    let power_grid = build_power_grid(GRID_SIZE, serial_num);
+    let precomputed = precompute_sums(&power_grid);
+
+    let coords = create_grid((0, 0), (GRID_SIZE, GRID_SIZE)).into_iter()
+    .filter(|p| {
+        p.0 + 2 < GRID_SIZE && p.1 + 2 < GRID_SIZE
+    })
+    .max_by_key(|&p| {
+        get_sum(&precomputed, p, (p.0+2, p.1+2))
+    }).unwrap();
+
+    (coords.1 + 1, coords.0 + 1)
+}
+
+fn part_2_solve(serial_num: i32, max_size: usize) -> ((usize, usize), usize) {
+    let power_grid = build_power_grid(GRID_SIZE, serial_num);
+    let precomputed = precompute_sums(&power_grid);
+
+    let coords = create_grid((0, 0), (GRID_SIZE, GRID_SIZE)).into_iter()
+    .flat_map(|p| (1..=max_size).map(move |s| (p, s)))
+    .filter(|((x, y), s)| {
+        x + s <= GRID_SIZE && y + s <= GRID_SIZE
+    })
+    .max_by_key(|(p, s)| {
+        get_sum(&precomputed, *p, (p.0+s-1, p.1+s-1))
+    }).unwrap();
+
+    (((coords.0).1 + 1, (coords.0).0 + 1), coords.1)
+}
+
+fn get_sum(precomputed: &PowerGrid, start: (usize, usize), end: (usize, usize)) -> i32 {
+    let mut res = precomputed[end.0][end.1];
+    if start.0 > 0 {
+        res -= precomputed[start.0-1][end.1];
+    }
+
+    if start.1 > 0 {
+        res -= precomputed[end.0][start.1-1];
+    }
+
+    if start.0 > 0 && start.1 > 0 {
+        res += precomputed[start.0-1][start.1-1];
+    }
+    
+    res
+}
+
+/// A square of fuel cells, displayed as `x,y,size` like the puzzle answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Square {
+    pub corner: Coord,
+    pub size: usize
+}
+
+impl fmt::Display for Square {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.corner, self.size)
+    }
+}
+
+pub struct Config {
+    pub serial: i32,
+    pub max_size: usize
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config { serial: 3463, max_size: GRID_SIZE }
+    }
+}
+
+/// The grid is generated from the serial number in `Config`, so there's no input to parse.
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = ();
+    type Config = Config;
+    type Part1 = Coord;
+    type Part2 = Square;
+
+    fn parse(_: &str) -> Result<(), ParseError> {
+        Ok(())
+    }
+
+    fn part1(_: &(), config: &Config) -> Coord {
+        let (x, y) = part_1_solve(config.serial);
+        Coord(x, y)
+    }
+
+    fn part2(_: &(), config: &Config) -> Square {
+        let ((x, y), size) = part_2_solve(config.serial, config.max_size);
+        Square { corner: Coord(x, y), size }
+    }
+}
+
+#[test]
+fn part_1_test() {
+    assert_eq!(part_1_solve(18), (33,45));
+    assert_eq!(part_1_solve(42), (21,61));
+}
+
+#[test]
+fn part_2_test() {
+    assert_eq!(part_2_solve(18, 300), ((90,269), 16));
+    assert_eq!(part_2_solve(42, 300), ((232,251), 12));
+}
+
+#[test]
+fn max_size_test() {
+    assert_eq!(part_2_solve(18, 3), ((33,45), 3));
+    for max_size in 1..=4 {
+        assert!(part_2_solve(42, max_size).1 <= max_size);
+    }
+
+    let (corner, size) = part_2_solve(18, 1);
+    assert_eq!(size, 1);
+    assert_eq!(get_cell_power(&corner, 18), 4);
+}
+
+#[test]
+fn answer_display_test() {
+    let config = Config { serial: 18, max_size: 300 };
+    assert_eq!(Day11::part1(&(), &config).to_string(), "33,45");
+    assert_eq!(Day11::part2(&(), &config).to_string(), "90,269,16");
+}
+
+#[test]
+fn cell_power_test() {
+    assert_eq!(get_cell_power(&(3, 5), 8), 4);
+    assert_eq!(get_cell_power(&(122,79), 57), -5);
+    assert_eq!(get_cell_power(&(217,196), 39), 0);
+    assert_eq!(get_cell_power(&(101,153), 71), 4);
+}
\ No newline at end of file
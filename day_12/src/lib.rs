@@ -0,0 +1,198 @@
+extern crate aoc_common;
+
+use std::collections::HashMap;
+use std::iter;
+use aoc_common::{ParseError, Solution};
+
+const SHORT_RUN: u64 = 20;
+const LONG_RUN: u64 = 50_000_000_000;
+
+/// Whether each five-pot neighbourhood grows a plant, indexed by reading the
+/// neighbourhood as a binary number with `#` as 1.
+pub type Rules = [bool; 32];
+
+/// A stretch of the tape where `plants[i]` is pot number `offset + i`. Kept
+/// trimmed to start and end with a plant, so the same shape compares equal
+/// wherever it has drifted to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pots {
+    offset: i64,
+    plants: Vec<bool>
+}
+
+impl Pots {
+    fn new(offset: i64, plants: Vec<bool>) -> Pots {
+        match (plants.iter().position(|&p| p), plants.iter().rposition(|&p| p)) {
+            (Some(first), Some(last)) => Pots {
+                offset: offset + first as i64,
+                plants: plants[first..=last].to_vec()
+            },
+            _ => Pots { offset: 0, plants: Vec::new() }
+        }
+    }
+
+    pub fn sum(&self) -> i64 {
+        self.plants.iter()
+            .enumerate()
+            .filter(|(_, &p)| p)
+            .map(|(i, _)| self.offset + i as i64)
+            .sum()
+    }
+
+    fn step(&self, rules: &Rules) -> Pots {
+        let padded = iter::repeat_n(false, 4)
+            .chain(self.plants.iter().cloned())
+            .chain(iter::repeat_n(false, 4))
+            .collect::<Vec<bool>>();
+
+        let next = padded.windows(5).map(|w| {
+            rules[w.iter().fold(0, |n, &p| n << 1 | p as usize)]
+        }).collect();
+
+        Pots::new(self.offset - 2, next)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Garden {
+    pots: Pots,
+    rules: Rules
+}
+
+fn parse_pots(line: &str, part: &str) -> Result<Vec<bool>, ParseError> {
+    part.char_indices().map(|(i, c)| match c {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => Err(ParseError::in_line(line, &part[i..i + c.len_utf8()], "'#' or '.'"))
+    }).collect()
+}
+
+fn parse_initial(line: &str) -> Result<Pots, ParseError> {
+    let expected = "'initial state: ' followed by pots";
+    match line.find(':') {
+        Some(i) if line[..i].trim() == "initial state" => {
+            let state = line[i + 1..].trim();
+            Ok(Pots::new(0, parse_pots(line, state)?))
+        },
+        _ => Err(ParseError::in_line(line, line, expected))
+    }
+}
+
+fn parse_rule(line: &str) -> Result<(usize, bool), ParseError> {
+    let expected = "a rule like '..#.. => #'";
+    let mut split = line.splitn(2, "=>").map(|s| s.trim());
+
+    match (split.next(), split.next()) {
+        (Some(pattern), Some(result)) => {
+            let pattern_pots = parse_pots(line, pattern)?;
+            if pattern_pots.len() != 5 {
+                return Err(ParseError::in_line(line, pattern, "a pattern of five pots"));
+            }
+
+            let grows = match parse_pots(line, result)?.as_slice() {
+                [p] => *p,
+                _ => return Err(ParseError::in_line(line, result, "a single pot"))
+            };
+
+            Ok((pattern_pots.iter().fold(0, |n, &p| n << 1 | p as usize), grows))
+        },
+        _ => Err(ParseError::in_line(line, line, expected))
+    }
+}
+
+fn parse_garden(input_str: &str) -> Result<Garden, ParseError> {
+    let mut lines = input_str.lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty());
+
+    let pots = match lines.next() {
+        Some((i, l)) => parse_initial(l).map_err(|e| e.at_line(i + 1))?,
+        None => return Err(ParseError::new("", "an initial state"))
+    };
+
+    let mut rules = [false; 32];
+    for (i, l) in lines {
+        let (pattern, grows) = parse_rule(l).map_err(|e| e.at_line(i + 1))?;
+        if pattern == 0 && grows {
+            return Err(ParseError::in_line(l, l, "empty pots to stay empty").at_line(i + 1));
+        }
+        rules[pattern] = grows;
+    }
+
+    Ok(Garden { pots, rules })
+}
+
+/// The pots after `generations` generations. Once a shape repeats, the
+/// row just drifts along the tape by a fixed amount each time round, so
+/// the remaining whole cycles are skipped by moving the offset directly.
+pub fn simulate(garden: &Garden, generations: u64) -> Pots {
+    let mut seen: HashMap<Vec<bool>, (u64, i64)> = HashMap::new();
+    let mut pots = garden.pots.clone();
+    let mut gen = 0;
+
+    while gen < generations {
+        if let Some((prev_gen, prev_offset)) = seen.insert(pots.plants.clone(), (gen, pots.offset)) {
+            let period = gen - prev_gen;
+            let cycles = (generations - gen) / period;
+            pots.offset += (pots.offset - prev_offset) * cycles as i64;
+            gen += cycles * period;
+            break;
+        }
+
+        pots = pots.step(&garden.rules);
+        gen += 1;
+    }
+
+    (gen..generations).fold(pots, |p, _| p.step(&garden.rules))
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Garden;
+    type Config = ();
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input_str: &str) -> Result<Garden, ParseError> {
+        parse_garden(input_str)
+    }
+
+    fn part1(garden: &Garden, _: &()) -> i64 {
+        simulate(garden, SHORT_RUN).sum()
+    }
+
+    fn part2(garden: &Garden, _: &()) -> i64 {
+        simulate(garden, LONG_RUN).sum()
+    }
+}
+
+#[test]
+fn simulate_test() {
+    let garden = parse_garden(include_str!("../input/test_input_1.txt")).unwrap();
+    let expected = "#....##....#####...#######....#.#..##".chars().map(|c| c == '#').collect();
+    assert_eq!(simulate(&garden, 20), Pots::new(-2, expected));
+
+    let brute_force = (0..500).fold(garden.pots.clone(), |p, _| p.step(&garden.rules));
+    assert_eq!(simulate(&garden, 500), brute_force);
+}
+
+#[test]
+fn part_1_test() {
+    assert_eq!(Day12::solve_part1(include_str!("../input/test_input_1.txt"), &()), Ok(325));
+}
+
+#[test]
+fn part_2_test() {
+    assert_eq!(Day12::solve_part2(include_str!("../input/test_input_1.txt"), &()), Ok(999999999374));
+}
+
+#[test]
+fn parse_error_test() {
+    let err = Day12::parse("initial state: #..#\n\n..#.. => #\n.#x.. => #").unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (4, Some(3), "x"));
+
+    assert_eq!(Day12::parse("initial state: #..#\n\n..#. => #").unwrap_err().line, 3);
+    assert_eq!(Day12::parse("initial state: #..#\n\n..... => #").unwrap_err().line, 3);
+    assert_eq!(Day12::parse("#..#").unwrap_err().line, 1);
+}
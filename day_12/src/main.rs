@@ -0,0 +1,10 @@
+extern crate aoc_common;
+extern crate day_12;
+
+use aoc_common::{input, solution, ParseError};
+use day_12::Day12;
+
+fn main() -> Result<(), ParseError> {
+    let input_str = input::load_from_args(12, concat!(env!("CARGO_MANIFEST_DIR"), "/.."));
+    solution::print_solution::<Day12>(&input_str, &Default::default())
+}
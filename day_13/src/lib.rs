@@ -0,0 +1,566 @@
+extern crate aoc_common;
+
+pub mod policy;
+pub mod snapshot;
+pub mod validate;
+pub mod viewer;
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::cmp::Ordering;
+use std::fmt;
+use aoc_common::{Coord, ParseError, Point, Solution};
+use policy::TurnPolicy;
+
+/// How long a run goes on looking for a crash before deciding the carts
+/// will never meet. Some turn policies leave carts circling on paths that
+/// never cross, and the combined state of several carts can take far too
+/// long to repeat to be worth detecting.
+pub const MAX_TICKS: u64 = 100_000;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Facing {
+    Up,
+    Down,
+    Left,
+    Right
+}
+
+/// Which way a cart goes through an intersection, relative to its facing.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Turn {
+    Left,
+    Straight,
+    Right
+}
+
+impl Facing {
+    fn turned(self, turn: Turn) -> Facing {
+        match (turn, self) {
+            (Turn::Straight, f) => f,
+            (Turn::Left, Facing::Up) | (Turn::Right, Facing::Down) => Facing::Left,
+            (Turn::Left, Facing::Down) | (Turn::Right, Facing::Up) => Facing::Right,
+            (Turn::Left, Facing::Left) | (Turn::Right, Facing::Right) => Facing::Down,
+            (Turn::Left, Facing::Right) | (Turn::Right, Facing::Left) => Facing::Up
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Track {
+    None,
+    Horizontal,
+    Vertical,
+    Turn(char),
+    Intersect
+}
+
+#[derive(Clone, Copy, Eq)]
+pub struct Cart {
+    id : usize,
+    curr_loc : Point,
+    curr_facing : Facing,
+    intersect_count : u32
+}
+
+impl fmt::Debug for Cart {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self.curr_loc)
+    }
+}
+
+impl PartialEq for Cart {
+    fn eq(&self, other: &Cart) -> bool {
+        self.curr_loc == other.curr_loc
+    }
+}
+
+impl PartialOrd for Cart {
+    fn partial_cmp(&self, other: &Cart) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Cart {
+    fn cmp(&self, other: &Cart) -> Ordering {
+        self.curr_loc.cmp(&other.curr_loc)
+    }
+}
+
+impl Cart {
+    fn new(id: usize, cart_char: char, loc: Point) -> Cart {
+        Cart {
+            id,
+            curr_loc : loc,
+            curr_facing : match cart_char {
+                '^' => Facing::Up,
+                'v' => Facing::Down,
+                '>' => Facing::Right,
+                '<' => Facing::Left,
+                _ => unreachable!()
+            },
+            intersect_count : 0
+        }
+    }
+
+    fn tick(&self, railway: &Rail, policy: &TurnPolicy) -> Cart {
+        let current_track = railway[self.curr_loc.0][self.curr_loc.1];
+
+        let new_facing = match current_track {
+            Track::Horizontal | Track::Vertical => self.curr_facing,
+            Track::Turn(c) => self.turn(c),
+            Track::Intersect => self.curr_facing.turned(policy.turn(self.id, self.intersect_count)),
+            Track::None => unreachable!()
+        };
+
+        let new_loc = match new_facing {
+            Facing::Up => (self.curr_loc.0-1, self.curr_loc.1),
+            Facing::Down => (self.curr_loc.0+1, self.curr_loc.1),
+            Facing::Left => (self.curr_loc.0, self.curr_loc.1-1),
+            Facing::Right => (self.curr_loc.0, self.curr_loc.1+1)
+        };
+
+        Cart {
+            id : self.id,
+            curr_loc : new_loc,
+            curr_facing : new_facing,
+            intersect_count : if current_track == Track::Intersect {
+                self.intersect_count + 1
+            } else {
+                self.intersect_count
+            }
+        }
+    }
+
+    fn turn(&self, turn_char: char) -> Facing {
+        match turn_char {
+            '\\' => match self.curr_facing {
+                Facing::Up => Facing::Left,
+                Facing::Down => Facing::Right,
+                Facing::Left => Facing::Up,
+                Facing::Right => Facing::Down
+            },
+            '/' => match self.curr_facing {
+                Facing::Up => Facing::Right,
+                Facing::Down => Facing::Left,
+                Facing::Left => Facing::Down,
+                Facing::Right => Facing::Up
+            },
+            _ => unreachable!()
+        }
+    }
+}
+
+pub type Rail = Vec<Vec<Track>>;
+pub type Carts = BTreeSet<Cart>;
+
+fn facing_char(facing: Facing) -> char {
+    match facing {
+        Facing::Up => '^',
+        Facing::Down => 'v',
+        Facing::Left => '<',
+        Facing::Right => '>'
+    }
+}
+
+/// The railway as it appears in the puzzle input, with carts drawn over the
+/// track and an `X` at each location in `crashes`.
+pub fn render(railway: &Rail, carts: &Carts, crashes: &[Coord]) -> String {
+    railway.iter().enumerate().map(|(y, row)| {
+        row.iter().enumerate().map(|(x, track)| {
+            if crashes.contains(&Coord(x, y)) {
+                return 'X';
+            }
+
+            match carts.iter().find(|c| c.curr_loc == (y, x)) {
+                Some(c) => facing_char(c.curr_facing),
+                None => match track {
+                    Track::None => ' ',
+                    Track::Horizontal => '-',
+                    Track::Vertical => '|',
+                    Track::Intersect => '+',
+                    Track::Turn(c) => *c
+                }
+            }
+        }).collect::<String>()
+    }).collect::<Vec<String>>().join("\n")
+}
+
+/// Writes a railway and its carts back out in the puzzle's input format.
+/// The track under a cart isn't kept, so this only round-trips carts on
+/// straight track, as they are at the start; use a `Snapshot` mid-run.
+pub fn serialize(railway: &Rail, carts: &Carts) -> String {
+    render(railway, carts, &[])
+}
+
+pub fn parse_railway(input_str: &str) -> (Rail, Carts) {
+    let mut carts = Carts::new();
+
+    let railway = input_str.lines().enumerate().map(|(y, l)| {
+        l.chars().enumerate().map(|(x, c)| {
+            match c {
+                '|' => Track::Vertical,
+                '-' => Track::Horizontal,
+                '\\' | '/' => Track::Turn(c),
+                '+' => Track::Intersect,
+                '>' | '<' => {
+                    carts.insert(Cart::new(carts.len(), c, (y, x)));
+                    Track::Horizontal
+                },
+                '^' | 'v' => {
+                    carts.insert(Cart::new(carts.len(), c, (y, x)));
+                    Track::Vertical
+                },
+                _ => Track::None
+            }
+        }).collect::<Vec<Track>>()
+    }).collect::<Rail>();
+
+    (railway, carts)
+}
+
+/// Two carts running into each other. `carts` holds the ids of the cart
+/// that moved and the cart it hit, where ids number the carts in reading
+/// order of their starting positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Collision {
+    pub tick: u64,
+    pub location: Coord,
+    pub carts: (usize, usize)
+}
+
+/// A cart passing through an intersection: how many it had been through
+/// before this one, and which way it went.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Decision {
+    pub tick: u64,
+    pub location: Coord,
+    pub intersect_count: u32,
+    pub turn: Turn
+}
+
+/// Every tile a cart occupied, starting with where it was placed, and the
+/// tick it crashed on, if it did.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CartHistory {
+    pub id: usize,
+    pub path: Vec<Coord>,
+    pub decisions: Vec<Decision>,
+    pub crashed: Option<u64>
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimulationLog {
+    pub ticks: u64,
+    pub collisions: Vec<Collision>,
+    /// Keyed by cart id, which needn't run from 0 once carts have crashed.
+    pub histories: BTreeMap<usize, CartHistory>
+}
+
+fn coord(loc: Point) -> Coord {
+    Coord(loc.1, loc.0)
+}
+
+/// The carts running on a railway. Carts that collide are removed on the
+/// spot, part way through the tick, as in part 2.
+pub struct Simulation<'a> {
+    railway: &'a Rail,
+    carts: Carts,
+    policy: TurnPolicy,
+    ticks: u64
+}
+
+impl<'a> Simulation<'a> {
+    pub fn new(railway: &'a Rail, carts: &Carts) -> Simulation<'a> {
+        Simulation::with_policy(railway, carts, TurnPolicy::default())
+    }
+
+    pub fn with_policy(railway: &'a Rail, carts: &Carts, policy: TurnPolicy) -> Simulation<'a> {
+        Simulation { railway, carts: carts.clone(), policy, ticks: 0 }
+    }
+
+    pub fn carts(&self) -> &Carts {
+        &self.carts
+    }
+
+    pub fn ticks(&self) -> u64 {
+        self.ticks
+    }
+
+    /// Moves every cart once and returns the collisions that happened.
+    pub fn tick(&mut self) -> Vec<Collision> {
+        self.tick_with(|_, _| {})
+    }
+
+    /// Moves every cart once, in reading order, against the live set of
+    /// carts, calling `on_move` with each cart before and after it moves.
+    fn tick_with<F: FnMut(&Cart, &Cart)>(&mut self, mut on_move: F) -> Vec<Collision> {
+        let order = self.carts.iter().cloned().collect::<Vec<Cart>>();
+        let mut collisions = Vec::new();
+        self.ticks += 1;
+
+        for c in order {
+            if !self.carts.remove(&c) {
+                continue;
+            }
+
+            let moved = c.tick(self.railway, &self.policy);
+            on_move(&c, &moved);
+
+            match self.carts.take(&moved) {
+                Some(hit) => collisions.push(Collision {
+                    tick: self.ticks,
+                    location: coord(moved.curr_loc),
+                    carts: (moved.id, hit.id)
+                }),
+                None => { self.carts.insert(moved); }
+            }
+        }
+
+        collisions
+    }
+
+    /// Runs until at most one cart is left, or `max_ticks` ticks have been
+    /// run in all, recording every collision and the path each cart took.
+    pub fn run_with_log(mut self, max_ticks: u64) -> SimulationLog {
+        let mut histories = self.carts.iter().map(|c| (c.id, CartHistory {
+            id: c.id,
+            path: vec!(coord(c.curr_loc)),
+            decisions: Vec::new(),
+            crashed: None
+        })).collect::<BTreeMap<usize, CartHistory>>();
+
+        let mut collisions = Vec::new();
+        let railway = self.railway;
+        let policy = self.policy.clone();
+
+        while self.carts.len() > 1 && self.ticks < max_ticks {
+            let tick_num = self.ticks + 1;
+            let crashed = self.tick_with(|before, after| {
+                let history = histories.get_mut(&before.id).expect("every cart has a history");
+                history.path.push(coord(after.curr_loc));
+
+                if railway[before.curr_loc.0][before.curr_loc.1] == Track::Intersect {
+                    history.decisions.push(Decision {
+                        tick: tick_num,
+                        location: coord(before.curr_loc),
+                        intersect_count: before.intersect_count,
+                        turn: policy.turn(before.id, before.intersect_count)
+                    });
+                }
+            });
+
+            for collision in crashed {
+                for id in &[collision.carts.0, collision.carts.1] {
+                    histories.get_mut(id).expect("every cart has a history").crashed = Some(collision.tick);
+                }
+                collisions.push(collision);
+            }
+        }
+
+        SimulationLog { ticks: self.ticks, collisions, histories }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FirstCrash {
+    Location(Coord),
+    NoCrash(u64)
+}
+
+impl fmt::Display for FirstCrash {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FirstCrash::Location(loc) => write!(f, "{}", loc),
+            FirstCrash::NoCrash(ticks) => write!(f, "no crash within {} ticks", ticks)
+        }
+    }
+}
+
+fn find_crash_location(railway: &Rail, carts: &Carts, policy: &TurnPolicy, max_ticks: u64) -> FirstCrash {
+    let mut sim = Simulation::with_policy(railway, carts, policy.clone());
+
+    while sim.ticks() < max_ticks {
+        if let Some(c) = sim.tick().first() {
+            return FirstCrash::Location(c.location);
+        }
+    }
+
+    FirstCrash::NoCrash(max_ticks)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LastCart {
+    Location(Coord),
+    NoneLeft,
+    /// How many carts were still running when the tick limit was reached.
+    StillRunning(usize, u64)
+}
+
+impl fmt::Display for LastCart {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LastCart::Location(loc) => write!(f, "{}", loc),
+            LastCart::NoneLeft => write!(f, "no cart left"),
+            LastCart::StillRunning(carts, ticks) => write!(f, "{} carts still running after {} ticks", carts, ticks)
+        }
+    }
+}
+
+/// Where the last cart is once every other cart has crashed. With an even
+/// number of carts they can all crash, leaving none.
+fn find_last_cart(railway: &Rail, carts: &Carts, policy: &TurnPolicy, max_ticks: u64) -> LastCart {
+    let mut sim = Simulation::with_policy(railway, carts, policy.clone());
+
+    while sim.carts().len() > 1 && sim.ticks() < max_ticks {
+        sim.tick();
+    }
+
+    match sim.carts().len() {
+        0 => LastCart::NoneLeft,
+        1 => LastCart::Location(coord(sim.carts().iter().next().unwrap().curr_loc)),
+        n => LastCart::StillRunning(n, max_ticks)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub policy: TurnPolicy,
+    pub max_ticks: u64
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config { policy: TurnPolicy::default(), max_ticks: MAX_TICKS }
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = (Rail, Carts);
+    type Config = Config;
+    type Part1 = FirstCrash;
+    type Part2 = LastCart;
+
+    /// Rejects any layout that could send a cart off the track, so the
+    /// simulation never has to.
+    fn parse(input_str: &str) -> Result<(Rail, Carts), ParseError> {
+        let (railway, carts) = parse_railway(input_str);
+        match validate::validate(&railway, &carts).first() {
+            Some(e) => Err(e.to_parse_error(input_str)),
+            None => Ok((railway, carts))
+        }
+    }
+
+    fn part1(input: &(Rail, Carts), config: &Config) -> FirstCrash {
+        find_crash_location(&input.0, &input.1, &config.policy, config.max_ticks)
+    }
+
+    fn part2(input: &(Rail, Carts), config: &Config) -> LastCart {
+        find_last_cart(&input.0, &input.1, &config.policy, config.max_ticks)
+    }
+}
+
+#[test]
+fn part_1_test() {
+    assert_eq!(Day13::solve_part1(include_str!("../input/test_input_1.txt"), &Config::default()), Ok(FirstCrash::Location(Coord(7,3))));
+}
+
+#[test]
+fn parse_error_test() {
+    let err = Day13::parse("->-").unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (1, Some(1), "-"));
+
+    let err = Day13::parse(include_str!("../input/test_input_2.txt")).unwrap_err();
+    assert_eq!((err.line, err.column), (1, Some(1)));
+
+    let (railway, carts) = parse_railway(include_str!("../input/test_input_2.txt"));
+    assert_eq!(find_crash_location(&railway, &carts, &TurnPolicy::Cycle, MAX_TICKS), FirstCrash::Location(Coord(2, 2)));
+}
+
+#[test]
+fn serialize_test() {
+    for fixture in &[include_str!("../input/test_input_1.txt"), include_str!("../input/test_input_2.txt"), include_str!("../input/test_input_3.txt")] {
+        let (railway, carts) = parse_railway(fixture);
+        assert_eq!(serialize(&railway, &carts), fixture.trim_end_matches('\n'));
+    }
+}
+
+#[test]
+fn swap_collision_test() {
+    let (railway, carts) = parse_railway("-><-");
+    assert_eq!(find_crash_location(&railway, &carts, &TurnPolicy::Cycle, MAX_TICKS), FirstCrash::Location(Coord(2, 0)));
+
+    let (railway, carts) = parse_railway("|\nv\n^\n|");
+    assert_eq!(find_crash_location(&railway, &carts, &TurnPolicy::Cycle, MAX_TICKS), FirstCrash::Location(Coord(0, 2)));
+}
+
+#[test]
+fn unmoved_collision_test() {
+    let (railway, carts) = parse_railway("->>-");
+    assert_eq!(find_crash_location(&railway, &carts, &TurnPolicy::Cycle, MAX_TICKS), FirstCrash::Location(Coord(2, 0)));
+
+    let (railway, carts) = parse_railway("|\nv\nv\n|");
+    assert_eq!(find_crash_location(&railway, &carts, &TurnPolicy::Cycle, MAX_TICKS), FirstCrash::Location(Coord(0, 2)));
+}
+
+#[test]
+fn part_2_test() {
+    assert_eq!(Day13::solve_part2(include_str!("../input/test_input_3.txt"), &Config::default()), Ok(LastCart::Location(Coord(6,4))));
+    assert_eq!(Day13::solve_part2("/->-<-\\\n\\-----/", &Config::default()), Ok(LastCart::NoneLeft));
+}
+
+#[test]
+fn find_last_cart_test() {
+    let (railway, carts) = parse_railway("->-<-");
+    assert_eq!(find_last_cart(&railway, &carts, &TurnPolicy::Cycle, MAX_TICKS), LastCart::NoneLeft);
+}
+
+#[test]
+fn run_with_log_test() {
+    let (railway, carts) = parse_railway(include_str!("../input/test_input_3.txt"));
+    let log = Simulation::new(&railway, &carts).run_with_log(MAX_TICKS);
+
+    assert_eq!(log.ticks, 3);
+    assert_eq!(log.collisions, vec!(
+        Collision { tick: 1, location: Coord(2, 0), carts: (1, 0) },
+        Collision { tick: 1, location: Coord(2, 4), carts: (5, 4) },
+        Collision { tick: 1, location: Coord(6, 4), carts: (6, 3) },
+        Collision { tick: 3, location: Coord(2, 4), carts: (7, 2) }
+    ));
+
+    let survivor = &log.histories[&8];
+    assert_eq!(survivor.crashed, None);
+    assert_eq!(survivor.path, vec!(Coord(5, 6), Coord(6, 6), Coord(6, 5), Coord(6, 4)));
+    assert_eq!(log.histories.values().filter(|h| h.crashed.is_some()).count(), 8);
+
+    let (railway, carts) = parse_railway(include_str!("../input/test_input_1.txt"));
+    let log = Simulation::new(&railway, &carts).run_with_log(MAX_TICKS);
+    assert_eq!(log.collisions, vec!(Collision { tick: 14, location: Coord(7, 3), carts: (0, 1) }));
+    assert_eq!(log.histories[&0].decisions, vec!(
+        Decision { tick: 5, location: Coord(4, 2), intersect_count: 0, turn: Turn::Left },
+        Decision { tick: 8, location: Coord(7, 2), intersect_count: 1, turn: Turn::Straight },
+        Decision { tick: 12, location: Coord(9, 4), intersect_count: 2, turn: Turn::Right }
+    ));
+    assert_eq!(log.histories[&1].path.len(), 15);
+}
+
+#[test]
+fn turn_policy_test() {
+    let (railway, carts) = parse_railway(include_str!("../input/test_input_1.txt"));
+    let input = (railway.clone(), carts.clone());
+    let sequence = Config { policy: "sequence:LSR".parse().unwrap(), ..Config::default() };
+    assert_eq!(Day13::part1(&input, &sequence), FirstCrash::Location(Coord(7, 3)));
+
+    let mut sim = Simulation::with_policy(&railway, &carts, TurnPolicy::Straight);
+    assert_eq!((0..100).flat_map(|_| sim.tick()).count(), 0);
+    assert_eq!(sim.carts().len(), 2);
+
+    let straight = Config { policy: TurnPolicy::Straight, max_ticks: 1000 };
+    assert_eq!(Day13::part1(&input, &straight), FirstCrash::NoCrash(1000));
+    assert_eq!(Day13::part2(&input, &straight), LastCart::StillRunning(2, 1000));
+    assert_eq!(Day13::part2(&input, &straight).to_string(), "2 carts still running after 1000 ticks");
+
+    let log = Simulation::with_policy(&railway, &carts, TurnPolicy::Straight).run_with_log(1000);
+    assert_eq!((log.ticks, log.collisions.len()), (1000, 0));
+}
@@ -0,0 +1,109 @@
+use std::fmt;
+use std::str::FromStr;
+
+use Turn;
+
+/// How a cart picks its way through an intersection, given how many
+/// intersections it has already been through.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum TurnPolicy {
+    /// Left, then straight, then right, as the puzzle describes.
+    #[default]
+    Cycle,
+    Straight,
+    /// A random turn, decided by the seed, the cart and the intersection
+    /// count alone so a run can be replayed or resumed exactly.
+    Random(u64),
+    /// The given turns in order, repeating; an empty sequence goes straight.
+    Sequence(Vec<Turn>)
+}
+
+/// SplitMix64's finaliser, enough to scatter nearby inputs.
+fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+impl TurnPolicy {
+    pub fn turn(&self, cart_id: usize, intersect_count: u32) -> Turn {
+        let pick = |n: u64| match n % 3 {
+            0 => Turn::Left,
+            1 => Turn::Straight,
+            _ => Turn::Right
+        };
+
+        match self {
+            TurnPolicy::Cycle => pick(u64::from(intersect_count)),
+            TurnPolicy::Straight => Turn::Straight,
+            TurnPolicy::Random(seed) => pick(mix(mix(seed ^ cart_id as u64) ^ u64::from(intersect_count))),
+            TurnPolicy::Sequence(turns) if turns.is_empty() => Turn::Straight,
+            TurnPolicy::Sequence(turns) => turns[intersect_count as usize % turns.len()]
+        }
+    }
+}
+
+impl fmt::Display for TurnPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TurnPolicy::Cycle => write!(f, "cycle"),
+            TurnPolicy::Straight => write!(f, "straight"),
+            TurnPolicy::Random(seed) => write!(f, "random:{}", seed),
+            TurnPolicy::Sequence(turns) => {
+                write!(f, "sequence:")?;
+                turns.iter().try_for_each(|t| match t {
+                    Turn::Left => write!(f, "L"),
+                    Turn::Straight => write!(f, "S"),
+                    Turn::Right => write!(f, "R")
+                })
+            }
+        }
+    }
+}
+
+/// Parses `cycle`, `straight`, `random:<seed>` or `sequence:<turns>`, where
+/// the turns are a string of `L`, `S` and `R`.
+impl FromStr for TurnPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<TurnPolicy, String> {
+        let mut split = s.splitn(2, ':');
+
+        match (split.next(), split.next()) {
+            (Some("cycle"), None) => Ok(TurnPolicy::Cycle),
+            (Some("straight"), None) => Ok(TurnPolicy::Straight),
+            (Some("random"), Some(seed)) => seed.parse().map(TurnPolicy::Random).map_err(|_| format!("invalid seed '{}'", seed)),
+            (Some("sequence"), Some(turns)) => turns.chars().map(|c| match c {
+                'L' => Ok(Turn::Left),
+                'S' => Ok(Turn::Straight),
+                'R' => Ok(Turn::Right),
+                _ => Err(format!("invalid turn '{}', expected L, S or R", c))
+            }).collect::<Result<Vec<Turn>, String>>().map(TurnPolicy::Sequence),
+            _ => Err(format!("unknown turn policy '{}'", s))
+        }
+    }
+}
+
+#[test]
+fn turn_test() {
+    let turns = |p: &TurnPolicy, id: usize| (0..6).map(|n| p.turn(id, n)).collect::<Vec<Turn>>();
+
+    assert_eq!(turns(&TurnPolicy::Cycle, 0), vec!(Turn::Left, Turn::Straight, Turn::Right, Turn::Left, Turn::Straight, Turn::Right));
+    assert_eq!(turns(&TurnPolicy::Straight, 0), vec!(Turn::Straight; 6));
+    assert_eq!(turns(&TurnPolicy::Sequence(vec!(Turn::Right, Turn::Left)), 0), vec!(Turn::Right, Turn::Left, Turn::Right, Turn::Left, Turn::Right, Turn::Left));
+    assert_eq!(turns(&TurnPolicy::Sequence(Vec::new()), 0), vec!(Turn::Straight; 6));
+
+    let random = TurnPolicy::Random(7);
+    assert_eq!(turns(&random, 3), turns(&random, 3));
+    assert_ne!((0..10).map(|id| turns(&random, id)).collect::<Vec<_>>(), (0..10).map(|id| turns(&TurnPolicy::Random(8), id)).collect::<Vec<_>>());
+}
+
+#[test]
+fn from_str_test() {
+    for s in &["cycle", "straight", "random:42", "sequence:LSRR"] {
+        assert_eq!(s.parse::<TurnPolicy>().unwrap().to_string(), *s);
+    }
+    assert!("random".parse::<TurnPolicy>().is_err());
+    assert!("sequence:LX".parse::<TurnPolicy>().is_err());
+    assert!("zigzag".parse::<TurnPolicy>().is_err());
+}
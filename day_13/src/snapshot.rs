@@ -0,0 +1,195 @@
+use std::fmt;
+
+use aoc_common::{parse_field, parse_pair, ParseError};
+use policy::TurnPolicy;
+use validate::validate;
+use {facing_char, parse_railway, render, Cart, Carts, Rail, Simulation};
+
+/// A simulation paused between ticks. Written out as a header of
+/// `ticks <N>`, `policy <POLICY>` and one `cart <id> <x>,<y> <facing>
+/// <intersections>` line per cart, then a blank line and the bare railway,
+/// so carts standing on curves and intersections keep the track under them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    pub railway: Rail,
+    pub carts: Carts,
+    pub ticks: u64,
+    pub policy: TurnPolicy
+}
+
+impl<'a> Simulation<'a> {
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            railway: self.railway.clone(),
+            carts: self.carts.clone(),
+            ticks: self.ticks,
+            policy: self.policy.clone()
+        }
+    }
+
+    /// Carries on from where `snapshot` was taken.
+    pub fn resume(snapshot: &'a Snapshot) -> Simulation<'a> {
+        Simulation {
+            railway: &snapshot.railway,
+            carts: snapshot.carts.clone(),
+            policy: snapshot.policy.clone(),
+            ticks: snapshot.ticks
+        }
+    }
+}
+
+impl fmt::Display for Snapshot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "ticks {}", self.ticks)?;
+        writeln!(f, "policy {}", self.policy)?;
+
+        let mut carts = self.carts.iter().collect::<Vec<&Cart>>();
+        carts.sort_by_key(|c| c.id);
+        for c in carts {
+            writeln!(f, "cart {} {},{} {} {}", c.id, c.curr_loc.1, c.curr_loc.0, facing_char(c.curr_facing), c.intersect_count)?;
+        }
+
+        write!(f, "\n{}", render(&self.railway, &Carts::new(), &[]))
+    }
+}
+
+fn parse_cart(line: &str) -> Result<Cart, ParseError> {
+    let expected = "a cart like 'cart <id> <x>,<y> <facing> <intersections>'";
+    let fields = line.split_whitespace().collect::<Vec<&str>>();
+
+    match fields.as_slice() {
+        ["cart", id, loc, facing, count] => {
+            let (x, y) = parse_pair::<usize>(loc, ',').map_err(|e| e.within(line, loc))?;
+            let facing = match facing.chars().collect::<Vec<char>>().as_slice() {
+                [c @ '^'] | [c @ 'v'] | [c @ '<'] | [c @ '>'] => *c,
+                _ => return Err(ParseError::in_line(line, facing, "one of ^ v < >"))
+            };
+
+            Ok(Cart {
+                intersect_count: parse_field(line, count, "an intersection count")?,
+                ..Cart::new(parse_field(line, id, "a cart id")?, facing, (y, x))
+            })
+        },
+        _ => Err(ParseError::in_line(line, line, expected))
+    }
+}
+
+impl Snapshot {
+    pub fn parse(input_str: &str) -> Result<Snapshot, ParseError> {
+        let mut ticks = None;
+        let mut policy = None;
+        let mut carts = Carts::new();
+        let mut lines = input_str.lines().enumerate();
+        let mut header_end = 1;
+
+        for (i, line) in lines.by_ref() {
+            header_end = i + 1;
+            let at_line = |e: ParseError| e.at_line(i + 1);
+            let rest = line.split_once(' ').map_or("", |(_, r)| r);
+
+            match line.split_whitespace().next() {
+                None => break,
+                Some("ticks") => ticks = Some(parse_field(line, rest, "a tick count").map_err(at_line)?),
+                Some("policy") => policy = Some(rest.trim().parse::<TurnPolicy>()
+                    .map_err(|e| at_line(ParseError::in_line(line, rest, &e)))?),
+                Some("cart") => {
+                    let cart = parse_cart(line).map_err(at_line)?;
+                    if carts.iter().any(|c| c.id == cart.id) {
+                        return Err(at_line(ParseError::in_line(line, line, "one cart per id")));
+                    }
+                    if !carts.insert(cart) {
+                        return Err(at_line(ParseError::in_line(line, line, "one cart per location")));
+                    }
+                },
+                Some(_) => return Err(at_line(ParseError::in_line(line, line, "ticks, policy or cart")))
+            }
+        }
+
+        // A missing header line is reported at the end of the header.
+        let missing = |expected: &str| ParseError::new("", expected).at_line(header_end);
+        let ticks = ticks.ok_or_else(|| missing("a 'ticks' line"))?;
+        let policy = policy.ok_or_else(|| missing("a 'policy' line"))?;
+
+        let map_start = header_end;
+        let map = lines.map(|(_, l)| l).collect::<Vec<&str>>().join("\n");
+        let (railway, map_carts) = parse_railway(&map);
+        if !map_carts.is_empty() {
+            let (row, line, col) = map.lines().enumerate()
+                .find_map(|(row, l)| l.find(|c| "^v<>".contains(c)).map(|col| (row, l, col)))
+                .expect("a cart parsed from the map has a cart tile");
+            let tile = &line[col..col + 1];
+            return Err(ParseError::in_line(line, tile, "a railway without carts, which belong in the header").at_line(map_start + row + 1));
+        }
+
+        if let Some(e) = validate(&railway, &carts).first() {
+            let err = e.to_parse_error(&map);
+            return Err(err.clone().at_line(err.line + map_start));
+        }
+
+        Ok(Snapshot { railway, carts, ticks, policy })
+    }
+}
+
+#[test]
+fn snapshot_round_trip_test() {
+    let (railway, carts) = parse_railway(include_str!("../input/test_input_1.txt"));
+    let mut sim = Simulation::with_policy(&railway, &carts, TurnPolicy::Random(5));
+    for _ in 0..6 {
+        sim.tick();
+    }
+
+    let saved = sim.snapshot().to_string();
+    let snapshot = Snapshot::parse(&saved).unwrap();
+    assert_eq!(snapshot, sim.snapshot());
+    assert_eq!(snapshot.to_string(), saved);
+
+    // Carts compare by location only, so check the rest of their state too.
+    let state = |carts: &Carts| {
+        let mut state = carts.iter().map(|c| (c.id, c.curr_loc, c.curr_facing, c.intersect_count)).collect::<Vec<_>>();
+        state.sort_by_key(|s| s.0);
+        state
+    };
+    assert_eq!(state(&snapshot.carts), state(&sim.snapshot().carts));
+    assert!(state(&snapshot.carts).iter().any(|s| s.3 > 0));
+
+    let mut resumed = Simulation::resume(&snapshot);
+    for _ in 0..20 {
+        assert_eq!(resumed.tick(), sim.tick());
+        assert_eq!(resumed.snapshot().to_string(), sim.snapshot().to_string());
+    }
+}
+
+#[test]
+fn resume_after_collision_test() {
+    let (railway, carts) = parse_railway(include_str!("../input/test_input_3.txt"));
+    let mut sim = Simulation::new(&railway, &carts);
+    assert_eq!(sim.tick().len(), 3);
+
+    let snapshot = Snapshot::parse(&sim.snapshot().to_string()).unwrap();
+    assert_eq!(snapshot.carts.iter().map(|c| c.id).collect::<Vec<usize>>(), vec!(2, 7, 8));
+
+    let log = Simulation::resume(&snapshot).run_with_log(::MAX_TICKS);
+    assert_eq!(log.histories.keys().cloned().collect::<Vec<usize>>(), vec!(2, 7, 8));
+    assert_eq!(log.collisions, vec!(::Collision { tick: 3, location: ::Coord(2, 4), carts: (7, 2) }));
+    assert_eq!(log.histories[&8].crashed, None);
+}
+
+#[test]
+fn snapshot_parse_error_test() {
+    let err = Snapshot::parse("ticks 3\npolicy cycle\ncart 0 4,x > 0\n\n->-").unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (3, Some(10), "x"));
+
+    assert_eq!(Snapshot::parse("ticks 3\npolicy zigzag\n\n---").unwrap_err().line, 2);
+    assert_eq!(Snapshot::parse("ticks 3\npolicy cycle\ncart 4 1,0 > 0\ncart 4 3,0 > 0\n\n/---\\\n\\---/").unwrap_err().line, 4);
+
+    let err = Snapshot::parse("ticks 3\npolicy cycle\n\n/-\\\n| v\n\\-/").unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (5, Some(3), "v"));
+
+    let err = Snapshot::parse("policy cycle\ncart 0 1,0 > 0\n\n/-\\\n| |\n\\-/").unwrap_err();
+    assert_eq!((err.line, err.text.as_str(), err.expected.as_str()), (3, "", "a 'ticks' line"));
+    let err = Snapshot::parse("ticks 3").unwrap_err();
+    assert_eq!((err.line, err.expected.as_str()), (1, "a 'policy' line"));
+
+    let err = Snapshot::parse("ticks 3\npolicy cycle\ncart 0 1,1 > 0\n\n/-\\\n| |\n\\-/").unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (6, Some(2), " "));
+}
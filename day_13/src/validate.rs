@@ -0,0 +1,192 @@
+use std::fmt;
+
+use aoc_common::{Coord, ParseError, Point};
+use {Carts, Facing, Rail, Track};
+
+const DIRECTIONS: [Facing; 4] = [Facing::Up, Facing::Down, Facing::Left, Facing::Right];
+
+/// A problem with a railway that would send a cart off the track.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutError {
+    DanglingEnd(Coord, Facing),
+    CartOffTrack(Coord),
+    CartFacingOffTrack(Coord, Facing),
+    UnconnectedCurve(Coord),
+    InconsistentIntersection(Coord, Facing)
+}
+
+impl fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LayoutError::DanglingEnd(c, d) => write!(f, "track at {} leads {:?} to no connecting track", c, d),
+            LayoutError::CartOffTrack(c) => write!(f, "cart at {} is not on any track", c),
+            LayoutError::CartFacingOffTrack(c, d) => write!(f, "cart at {} faces {:?}, across its track", c, d),
+            LayoutError::UnconnectedCurve(c) => write!(f, "curve at {} doesn't join track on both of its sides", c),
+            LayoutError::InconsistentIntersection(c, d) => write!(f, "intersection at {} has no track {:?} of it", c, d)
+        }
+    }
+}
+
+impl LayoutError {
+    pub fn location(&self) -> Coord {
+        match *self {
+            LayoutError::DanglingEnd(c, _) | LayoutError::CartOffTrack(c) | LayoutError::CartFacingOffTrack(c, _) |
+            LayoutError::UnconnectedCurve(c) | LayoutError::InconsistentIntersection(c, _) => c
+        }
+    }
+
+    fn expected(&self) -> String {
+        match self {
+            LayoutError::DanglingEnd(_, d) => format!("track joining more track {:?} of it", d),
+            LayoutError::CartOffTrack(_) => "a cart on track".to_string(),
+            LayoutError::CartFacingOffTrack(_, d) => format!("track a cart facing {:?} can follow", d),
+            LayoutError::UnconnectedCurve(_) => "a curve joining track on both of its sides".to_string(),
+            LayoutError::InconsistentIntersection(_, d) => format!("an intersection with track {:?} of it", d)
+        }
+    }
+
+    /// The error as a `ParseError` pointing at its tile in `map`, the
+    /// railway as it was written.
+    pub fn to_parse_error(&self, map: &str) -> ParseError {
+        let Coord(x, y) = self.location();
+        let line = map.lines().nth(y).unwrap_or("");
+        let tile = line.char_indices().nth(x).map_or(&line[line.len()..], |(i, c)| &line[i..i + c.len_utf8()]);
+        ParseError::in_line(line, tile, &self.expected()).at_line(y + 1)
+    }
+}
+
+fn opposite(dir: Facing) -> Facing {
+    match dir {
+        Facing::Up => Facing::Down,
+        Facing::Down => Facing::Up,
+        Facing::Left => Facing::Right,
+        Facing::Right => Facing::Left
+    }
+}
+
+fn track_at(railway: &Rail, loc: Point) -> Track {
+    railway.get(loc.0).and_then(|row| row.get(loc.1)).cloned().unwrap_or(Track::None)
+}
+
+fn neighbour(loc: Point, dir: Facing) -> Option<Point> {
+    match dir {
+        Facing::Up if loc.0 > 0 => Some((loc.0 - 1, loc.1)),
+        Facing::Left if loc.1 > 0 => Some((loc.0, loc.1 - 1)),
+        Facing::Down => Some((loc.0 + 1, loc.1)),
+        Facing::Right => Some((loc.0, loc.1 + 1)),
+        _ => None
+    }
+}
+
+/// The two ways a curve can be laid, as the pair of sides it joins.
+fn curve_shapes(c: char) -> [(Facing, Facing); 2] {
+    match c {
+        '/' => [(Facing::Left, Facing::Up), (Facing::Right, Facing::Down)],
+        _ => [(Facing::Left, Facing::Down), (Facing::Up, Facing::Right)]
+    }
+}
+
+/// Whether the track could join something on its `dir` side, taking any
+/// curve to be laid whichever way suits.
+fn could_open(track: Track, dir: Facing) -> bool {
+    match track {
+        Track::Horizontal => dir == Facing::Left || dir == Facing::Right,
+        Track::Vertical => dir == Facing::Up || dir == Facing::Down,
+        Track::Intersect | Track::Turn(_) => true,
+        Track::None => false
+    }
+}
+
+/// Which way a curve is laid, worked out from the track around it.
+fn curve_sides(railway: &Rail, loc: Point, c: char) -> Option<(Facing, Facing)> {
+    let joins = |dir: Facing| neighbour(loc, dir).is_some_and(|n| could_open(track_at(railway, n), opposite(dir)));
+    curve_shapes(c).iter().cloned().find(|&(a, b)| joins(a) && joins(b))
+}
+
+fn sides(railway: &Rail, loc: Point) -> Vec<Facing> {
+    match track_at(railway, loc) {
+        Track::Horizontal => vec!(Facing::Left, Facing::Right),
+        Track::Vertical => vec!(Facing::Up, Facing::Down),
+        Track::Intersect => DIRECTIONS.to_vec(),
+        Track::Turn(c) => curve_sides(railway, loc, c).map_or(Vec::new(), |(a, b)| vec!(a, b)),
+        Track::None => Vec::new()
+    }
+}
+
+fn connects(railway: &Rail, loc: Point, dir: Facing) -> bool {
+    neighbour(loc, dir).is_some_and(|n| sides(railway, n).contains(&opposite(dir)))
+}
+
+/// Every problem with the layout, in reading order, so a railway can be
+/// checked before carts are set running on it.
+pub fn validate(railway: &Rail, carts: &Carts) -> Vec<LayoutError> {
+    let mut errors = Vec::new();
+
+    for (y, row) in railway.iter().enumerate() {
+        for (x, &track) in row.iter().enumerate() {
+            let loc = (y, x);
+            let dangling = DIRECTIONS.iter().cloned().filter(|&d| !connects(railway, loc, d));
+
+            match track {
+                Track::None => {},
+                Track::Intersect => errors.extend(dangling.map(|d| LayoutError::InconsistentIntersection(Coord(x, y), d))),
+                Track::Turn(c) if curve_sides(railway, loc, c).is_none() => errors.push(LayoutError::UnconnectedCurve(Coord(x, y))),
+                _ => {
+                    let own = sides(railway, loc);
+                    errors.extend(dangling.filter(|d| own.contains(d)).map(|d| LayoutError::DanglingEnd(Coord(x, y), d)));
+                }
+            }
+        }
+    }
+
+    errors.extend(carts.iter().filter_map(|c| {
+        let loc = Coord(c.curr_loc.1, c.curr_loc.0);
+        match track_at(railway, c.curr_loc) {
+            Track::None => Some(LayoutError::CartOffTrack(loc)),
+            _ if !sides(railway, c.curr_loc).contains(&opposite(c.curr_facing)) => Some(LayoutError::CartFacingOffTrack(loc, c.curr_facing)),
+            _ => None
+        }
+    }));
+
+    errors
+}
+
+#[test]
+fn validate_test() {
+    for fixture in &[include_str!("../input/test_input_1.txt"), include_str!("../input/test_input_3.txt")] {
+        let (railway, carts) = ::parse_railway(fixture);
+        assert_eq!(validate(&railway, &carts), Vec::new());
+    }
+
+    let (railway, carts) = ::parse_railway("->-");
+    assert_eq!(validate(&railway, &carts), vec!(
+        LayoutError::DanglingEnd(Coord(0, 0), Facing::Left),
+        LayoutError::DanglingEnd(Coord(2, 0), Facing::Right)
+    ));
+
+    let (railway, carts) = ::parse_railway("/-\\ \n| | /\n\\-/");
+    assert_eq!(validate(&railway, &carts), vec!(LayoutError::UnconnectedCurve(Coord(4, 1))));
+
+    let (railway, carts) = ::parse_railway("/-\\\n+-+\n\\-/");
+    assert_eq!(validate(&railway, &carts), vec!(
+        LayoutError::InconsistentIntersection(Coord(0, 1), Facing::Left),
+        LayoutError::InconsistentIntersection(Coord(2, 1), Facing::Right)
+    ));
+
+    let (mut railway, carts) = ::parse_railway("/>\\\n| |\n\\-/");
+    railway[0][1] = Track::None;
+    assert_eq!(validate(&railway, &carts).last(), Some(&LayoutError::CartOffTrack(Coord(1, 0))));
+
+    let (mut railway, carts) = ::parse_railway("/>\\\n| |\n\\-/");
+    railway[0][1] = Track::Vertical;
+    assert_eq!(validate(&railway, &carts).last(), Some(&LayoutError::CartFacingOffTrack(Coord(1, 0), Facing::Right)));
+}
+
+#[test]
+fn to_parse_error_test() {
+    let map = "/-\\\n+-+\n\\-/";
+    let (railway, carts) = ::parse_railway(map);
+    let err = validate(&railway, &carts)[0].to_parse_error(map);
+    assert_eq!((err.line, err.column, err.text.as_str()), (2, Some(1), "+"));
+    assert_eq!(err.to_string(), "line 2, column 1: expected an intersection with track Left of it, found \"+\"");
+}
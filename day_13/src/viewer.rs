@@ -0,0 +1,135 @@
+use std::io::{self, BufRead, Write};
+
+use {render, Carts, Config, Rail, Simulation};
+
+pub const HELP: &str = "\
+Commands:
+    s, <enter>      Step one tick
+    n <N>, <N>      Run N ticks
+    c               Run to the next collision
+    q               Quit";
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Step(usize),
+    UntilCollision,
+    Help,
+    Quit
+}
+
+fn parse_command(line: &str) -> Option<Command> {
+    let mut words = line.split_whitespace();
+
+    match (words.next(), words.next(), words.next()) {
+        (None, _, _) | (Some("s"), None, _) => Some(Command::Step(1)),
+        (Some("n"), Some(n), None) => n.parse().ok().map(Command::Step),
+        (Some("c"), None, _) => Some(Command::UntilCollision),
+        (Some("h"), None, _) | (Some("?"), None, _) => Some(Command::Help),
+        (Some("q"), None, _) => Some(Command::Quit),
+        (Some(n), None, _) => n.parse().ok().map(Command::Step),
+        _ => None
+    }
+}
+
+/// Steps through the simulation under the control of commands read from
+/// `input`, drawing the railway to `output` after every command. Crashed
+/// carts are removed as in part 2 and drawn as `X` on the tick they crash.
+/// Running to the next collision gives up after `config.max_ticks` ticks.
+pub fn step_through<R: BufRead, W: Write>(railway: &Rail, carts: &Carts, config: &Config, input: R, mut output: W) -> io::Result<()> {
+    let mut sim = Simulation::with_policy(railway, carts, config.policy.clone());
+    let mut crashes = Vec::new();
+    let mut lines = input.lines();
+
+    writeln!(output, "{}\n\n{}", HELP, render(railway, sim.carts(), &crashes))?;
+
+    loop {
+        write!(output, "tick {}, {} carts> ", sim.ticks(), sim.carts().len())?;
+        output.flush()?;
+
+        let line = match lines.next() {
+            Some(line) => line?,
+            None => return Ok(())
+        };
+
+        let (ticks, until_collision) = match parse_command(&line) {
+            Some(Command::Step(n)) => (n as u64, false),
+            Some(Command::UntilCollision) if sim.carts().len() < 2 => {
+                writeln!(output, "fewer than two carts left, no more collisions")?;
+                continue;
+            },
+            Some(Command::UntilCollision) => (config.max_ticks, true),
+            Some(Command::Help) => {
+                writeln!(output, "{}", HELP)?;
+                continue;
+            },
+            Some(Command::Quit) => return Ok(()),
+            None => {
+                writeln!(output, "unknown command '{}', h for help", line.trim())?;
+                continue;
+            }
+        };
+
+        let mut collided = false;
+        for _ in 0..ticks {
+            let collisions = sim.tick();
+            for c in &collisions {
+                writeln!(output, "tick {}: collision at {}", c.tick, c.location)?;
+            }
+
+            crashes = collisions.iter().map(|c| c.location).collect();
+            collided = !crashes.is_empty();
+            if collided && until_collision {
+                break;
+            }
+        }
+
+        if until_collision && !collided {
+            writeln!(output, "no collision within {} ticks", ticks)?;
+        }
+
+        writeln!(output, "{}", render(railway, sim.carts(), &crashes))?;
+    }
+}
+
+#[test]
+fn parse_command_test() {
+    assert_eq!(parse_command(""), Some(Command::Step(1)));
+    assert_eq!(parse_command("n 5"), Some(Command::Step(5)));
+    assert_eq!(parse_command("12"), Some(Command::Step(12)));
+    assert_eq!(parse_command("c"), Some(Command::UntilCollision));
+    assert_eq!(parse_command("q"), Some(Command::Quit));
+    assert_eq!(parse_command("n five"), None);
+    assert_eq!(parse_command("x y"), None);
+}
+
+#[test]
+fn step_through_test() {
+    let (railway, carts) = ::parse_railway(include_str!("../input/test_input_1.txt"));
+    let mut output = Vec::new();
+    step_through(&railway, &carts, &Config::default(), "s\nc\nq\n".as_bytes(), &mut output).unwrap();
+
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains("tick 14: collision at 7,3"));
+    assert!(output.contains('X'));
+    assert!(output.ends_with("tick 14, 0 carts> "));
+}
+
+#[test]
+fn step_through_without_collision_test() {
+    use aoc_common::Solution;
+
+    let (railway, carts) = ::Day13::parse("/>\\\n| |\n\\-/\n/<\\\n| |\n\\-/").unwrap();
+    let config = Config { max_ticks: 50, ..Config::default() };
+    let mut output = Vec::new();
+    step_through(&railway, &carts, &config, "c\nc\nq\n".as_bytes(), &mut output).unwrap();
+
+    let output = String::from_utf8(output).unwrap();
+    assert_eq!(output.matches("no collision within 50 ticks").count(), 2);
+    assert!(output.ends_with("tick 100, 2 carts> "));
+
+    let (railway, carts) = ::parse_railway(include_str!("../input/test_input_1.txt"));
+    let config = Config { policy: ::policy::TurnPolicy::Straight, max_ticks: 20 };
+    let mut output = Vec::new();
+    step_through(&railway, &carts, &config, "c\nq\n".as_bytes(), &mut output).unwrap();
+    assert!(String::from_utf8(output).unwrap().contains("no collision within 20 ticks"));
+}
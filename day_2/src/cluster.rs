@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use serde_json;
+
+use distance::Distance;
+use pairs_within;
+
+/// IDs linked to each other, directly or through other members, by being
+/// within the edit threshold or identical.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Cluster {
+    /// Members in input order.
+    pub members: Vec<String>,
+    /// The letters of the first member that every other member keeps.
+    pub shared: String,
+    /// The positions in the first member's ID of the letters that aren't shared.
+    pub differing: Vec<usize>
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ClusterReport {
+    pub distance: Distance,
+    pub max_edits: usize,
+    /// Clusters of two or more IDs, in order of their first member.
+    pub clusters: Vec<Cluster>,
+    /// How many IDs have no near-duplicate at all.
+    pub unmatched: usize
+}
+
+fn root(parents: &mut [usize], mut i: usize) -> usize {
+    while parents[i] != i {
+        parents[i] = parents[parents[i]];
+        i = parents[i];
+    }
+    i
+}
+
+fn cluster(ids: &[String], members: &[usize], distance: Distance) -> Cluster {
+    let first = &ids[members[0]];
+    let mut kept = vec!(true; first.chars().count());
+
+    for &m in &members[1..] {
+        let keeps = distance.kept_positions(first, &ids[m]);
+        for (p, k) in kept.iter_mut().enumerate() {
+            *k = *k && keeps.contains(&p);
+        }
+    }
+
+    Cluster {
+        members: members.iter().map(|&m| ids[m].clone()).collect(),
+        shared: first.chars().zip(&kept).filter(|&(_, &k)| k).map(|(c, _)| c).collect(),
+        differing: (0..kept.len()).filter(|&p| !kept[p]).collect()
+    }
+}
+
+/// Every ID grouped with its near-duplicates, taking the links between them
+/// to be transitive. IDs with no near-duplicate make a cluster of their own.
+pub fn clusters(ids: &[String], distance: Distance, max_edits: usize) -> Vec<Cluster> {
+    let mut parents = (0..ids.len()).collect::<Vec<usize>>();
+    let mut first_seen: HashMap<&str, usize> = HashMap::new();
+
+    let duplicates = ids.iter().enumerate()
+        .filter_map(|(j, id)| first_seen.insert(id, j).map(|i| (i, j)))
+        .collect::<Vec<(usize, usize)>>();
+
+    for (i, j) in pairs_within(ids, distance, max_edits).into_iter().chain(duplicates) {
+        let (a, b) = (root(&mut parents, i), root(&mut parents, j));
+        parents[a.max(b)] = a.min(b);
+    }
+
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    let mut group_of: HashMap<usize, usize> = HashMap::new();
+    for i in 0..ids.len() {
+        let r = root(&mut parents, i);
+        let g = *group_of.entry(r).or_insert(groups.len());
+        if g == groups.len() {
+            groups.push(Vec::new());
+        }
+        groups[g].push(i);
+    }
+
+    groups.iter().map(|members| cluster(ids, members, distance)).collect()
+}
+
+pub fn cluster_report(ids: &[String], distance: Distance, max_edits: usize) -> ClusterReport {
+    let (clusters, single): (Vec<Cluster>, Vec<Cluster>) = clusters(ids, distance, max_edits)
+        .into_iter()
+        .partition(|c| c.members.len() > 1);
+
+    ClusterReport { distance, max_edits, clusters, unmatched: single.len() }
+}
+
+impl ClusterReport {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("a cluster report is always valid JSON")
+    }
+}
+
+impl fmt::Display for ClusterReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "distance: {}, at most {} edits", self.distance, self.max_edits)?;
+        writeln!(f, "clusters: {}", self.clusters.len())?;
+        write!(f, "unmatched IDs: {}", self.unmatched)?;
+
+        for (n, c) in self.clusters.iter().enumerate() {
+            let positions = c.differing.iter().map(|p| p.to_string()).collect::<Vec<String>>();
+            write!(f, "\n\ncluster {}: {} IDs, shared '{}', differing at [{}]", n + 1, c.members.len(), c.shared, positions.join(", "))?;
+            for m in &c.members {
+                write!(f, "\n    {}", m)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn clusters_test() {
+    use aoc_common::Solution;
+    let ids = ::Day2::parse(include_str!("../input/test_input_2.txt")).unwrap();
+    let found = clusters(&ids, Distance::Hamming, 1);
+    assert_eq!(found.len(), ids.len() - 1);
+    assert_eq!(found[1], Cluster { members: vec!("fghij".to_string(), "fguij".to_string()), shared: "fgij".to_string(), differing: vec!(2) });
+    assert_eq!(found[0], Cluster { members: vec!("abcde".to_string()), shared: "abcde".to_string(), differing: Vec::new() });
+
+    let ids = ["abcde", "vwxyz", "abxde", "abcde", "abxdf", "vwxy"].iter().map(|s| s.to_string()).collect::<Vec<String>>();
+    let members = |cs: &[Cluster]| cs.iter().map(|c| c.members.len()).collect::<Vec<usize>>();
+    assert_eq!(members(&clusters(&ids, Distance::Hamming, 1)), vec!(4, 1, 1));
+    assert_eq!(members(&clusters(&ids, Distance::Levenshtein, 1)), vec!(4, 2));
+    assert_eq!(members(&clusters(&ids, Distance::Hamming, 0)), vec!(2, 1, 1, 1, 1));
+
+    let chain = &clusters(&ids, Distance::Hamming, 1)[0];
+    assert_eq!((chain.shared.as_str(), &chain.differing), ("abd", &vec!(2, 4)));
+}
+
+#[test]
+fn cluster_report_test() {
+    use aoc_common::Solution;
+    let ids = ::Day2::parse(include_str!("../input/test_input_2.txt")).unwrap();
+    let report = cluster_report(&ids, Distance::Hamming, 1);
+    assert_eq!(report.unmatched, 5);
+    assert_eq!(report.to_string(), "distance: hamming, at most 1 edits\nclusters: 1\nunmatched IDs: 5\n\n\
+        cluster 1: 2 IDs, shared 'fgij', differing at [2]\n    fghij\n    fguij");
+
+    let json = serde_json::from_str::<serde_json::Value>(&report.to_json()).unwrap();
+    assert_eq!(json["distance"], "hamming");
+    assert_eq!(json["clusters"][0]["members"][1], "fguij");
+    assert_eq!(json["clusters"][0]["differing"][0], 2);
+}
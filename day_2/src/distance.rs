@@ -0,0 +1,161 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// How many edits it takes to turn one ID into another.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Distance {
+    /// Letters that differ position by position. Only defined between IDs of
+    /// the same length.
+    #[default]
+    Hamming,
+    /// Letters inserted, deleted or substituted.
+    Levenshtein,
+    /// As Levenshtein, with a swap of two neighbouring letters counting as a
+    /// single edit. This is the optimal string alignment variant, where no
+    /// letter is edited more than once.
+    Damerau
+}
+
+/// The fewest edits to turn each prefix `a[..i]` into each prefix `b[..j]`.
+fn edit_table(a: &[char], b: &[char], transpose: bool) -> Vec<Vec<usize>> {
+    let mut d = vec!(vec!(0; b.len() + 1); a.len() + 1);
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = (a[i - 1] != b[j - 1]) as usize;
+            d[i][j] = (d[i - 1][j] + 1).min(d[i][j - 1] + 1).min(d[i - 1][j - 1] + cost);
+
+            if transpose && i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+
+    d
+}
+
+/// The positions in `a` left untouched by a cheapest way of editing `a`
+/// into `b`, found by walking the edit table back from the end.
+fn kept_positions(a: &[char], b: &[char], transpose: bool) -> Vec<usize> {
+    let d = edit_table(a, b, transpose);
+    let (mut i, mut j) = (a.len(), b.len());
+    let mut kept = Vec::new();
+
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 && a[i - 1] == b[j - 1] && d[i][j] == d[i - 1][j - 1] {
+            kept.push(i - 1);
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && j > 0 && d[i][j] == d[i - 1][j - 1] + 1 {
+            i -= 1;
+            j -= 1;
+        } else if transpose && i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] && d[i][j] == d[i - 2][j - 2] + 1 {
+            i -= 2;
+            j -= 2;
+        } else if i > 0 && d[i][j] == d[i - 1][j] + 1 {
+            i -= 1;
+        } else {
+            j -= 1;
+        }
+    }
+
+    kept.reverse();
+    kept
+}
+
+impl Distance {
+    /// The distance between two IDs, or `None` where it isn't defined.
+    pub fn between(self, a: &str, b: &str) -> Option<usize> {
+        let a = a.chars().collect::<Vec<char>>();
+        let b = b.chars().collect::<Vec<char>>();
+
+        match self {
+            Distance::Hamming if a.len() != b.len() => None,
+            Distance::Hamming => Some(a.iter().zip(&b).filter(|(x, y)| x != y).count()),
+            Distance::Levenshtein => Some(edit_table(&a, &b, false)[a.len()][b.len()]),
+            Distance::Damerau => Some(edit_table(&a, &b, true)[a.len()][b.len()])
+        }
+    }
+
+    /// The positions in `a` of the letters it keeps in common with `b`:
+    /// those matching position by position for Hamming, or those no edit
+    /// touches for the others.
+    pub fn kept_positions(self, a: &str, b: &str) -> Vec<usize> {
+        let a = a.chars().collect::<Vec<char>>();
+        let b = b.chars().collect::<Vec<char>>();
+
+        match self {
+            Distance::Hamming => (0..a.len().min(b.len())).filter(|&i| a[i] == b[i]).collect(),
+            Distance::Levenshtein => kept_positions(&a, &b, false),
+            Distance::Damerau => kept_positions(&a, &b, true)
+        }
+    }
+
+    /// The letters two IDs have in common, in order.
+    pub fn shared_letters(self, a: &str, b: &str) -> String {
+        let chars = a.chars().collect::<Vec<char>>();
+        self.kept_positions(a, b).iter().map(|&i| chars[i]).collect()
+    }
+}
+
+impl fmt::Display for Distance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Distance::Hamming => write!(f, "hamming"),
+            Distance::Levenshtein => write!(f, "levenshtein"),
+            Distance::Damerau => write!(f, "damerau")
+        }
+    }
+}
+
+impl FromStr for Distance {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Distance, String> {
+        match s {
+            "hamming" => Ok(Distance::Hamming),
+            "levenshtein" => Ok(Distance::Levenshtein),
+            "damerau" => Ok(Distance::Damerau),
+            _ => Err(format!("unknown distance '{}', expected hamming, levenshtein or damerau", s))
+        }
+    }
+}
+
+#[test]
+fn between_test() {
+    let all = |a: &str, b: &str| [Distance::Hamming, Distance::Levenshtein, Distance::Damerau].iter()
+        .map(|d| d.between(a, b))
+        .collect::<Vec<Option<usize>>>();
+
+    assert_eq!(all("abcde", "abcde"), vec!(Some(0), Some(0), Some(0)));
+    assert_eq!(all("abcde", "axcye"), vec!(Some(2), Some(2), Some(2)));
+    assert_eq!(all("abcde", "bacde"), vec!(Some(2), Some(2), Some(1)));
+    assert_eq!(all("abcde", "abde"), vec!(None, Some(1), Some(1)));
+    assert_eq!(all("abcde", "xabcd"), vec!(Some(5), Some(2), Some(2)));
+    assert_eq!(all("", "abc"), vec!(None, Some(3), Some(3)));
+    assert_eq!(all("ca", "abc"), vec!(None, Some(3), Some(3)));
+}
+
+#[test]
+fn shared_letters_test() {
+    assert_eq!(Distance::Hamming.shared_letters("fghij", "fguij"), "fgij");
+    assert_eq!(Distance::Levenshtein.shared_letters("abcde", "abde"), "abde");
+    assert_eq!(Distance::Levenshtein.shared_letters("abcde", "xabcd"), "abcd");
+    assert_eq!(Distance::Damerau.shared_letters("abcde", "bacde"), "cde");
+    assert_eq!(Distance::Levenshtein.kept_positions("abcde", "xabcd"), vec!(0, 1, 2, 3));
+}
+
+#[test]
+fn from_str_test() {
+    for s in &["hamming", "levenshtein", "damerau"] {
+        assert_eq!(s.parse::<Distance>().unwrap().to_string(), *s);
+    }
+    assert!("euclid".parse::<Distance>().is_err());
+}
@@ -0,0 +1,213 @@
+extern crate aoc_common;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
+
+pub mod cluster;
+pub mod distance;
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use aoc_common::{ParseError, Solution};
+use distance::Distance;
+
+/// How many IDs contain some letter exactly `n` times, for each requested
+/// `n`, and the product of those counts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Checksum {
+    pub counts: Vec<(usize, u64)>,
+    pub product: Product
+}
+
+/// The product of the checksum counts. Each count fits in a `u64`, but with
+/// enough multiplicities their product may not, and that is reported
+/// rather than wrapped or saturated to a wrong checksum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Product {
+    Value(u64),
+    Overflow
+}
+
+impl fmt::Display for Product {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Product::Value(n) => write!(f, "{}", n),
+            Product::Overflow => write!(f, "overflow: the checksum is larger than {}", u64::MAX)
+        }
+    }
+}
+
+pub fn checksum(ids: &[String], multiplicities: &[usize]) -> Checksum {
+    let letter_counts = ids.iter().map(|line| {
+        line.chars().fold(HashMap::new(), |mut map, c| {
+            *map.entry(c).or_insert(0) += 1;
+            map
+        }).into_values().collect::<HashSet<usize>>()
+    }).collect::<Vec<HashSet<usize>>>();
+
+    let counts = multiplicities.iter().map(|&m| {
+        (m, letter_counts.iter().filter(|c| c.contains(&m)).count() as u64)
+    }).collect::<Vec<(usize, u64)>>();
+
+    Checksum {
+        product: counts.iter()
+            .try_fold(1u64, |acc, &(_, n)| acc.checked_mul(n))
+            .map_or(Product::Overflow, Product::Value),
+        counts
+    }
+}
+
+/// Every pair of IDs, as indices into `ids` in order, that differ in exactly
+/// one position. Each ID is filed under each of its positions with that
+/// letter left out, so a pair shares exactly one of those keys and is found
+/// without comparing every ID against every other.
+pub fn near_duplicates(ids: &[String]) -> Vec<(usize, usize)> {
+    let mut buckets: HashMap<(usize, String), Vec<(usize, char)>> = HashMap::new();
+
+    for (i, id) in ids.iter().enumerate() {
+        let chars = id.chars().collect::<Vec<char>>();
+        for (p, &c) in chars.iter().enumerate() {
+            let masked = chars[..p].iter().chain(&chars[p + 1..]).collect::<String>();
+            buckets.entry((p, masked)).or_default().push((i, c));
+        }
+    }
+
+    let mut pairs = buckets.values().flat_map(|bucket| {
+        bucket.iter().enumerate().flat_map(move |(n, &(i, a))| {
+            bucket[n + 1..].iter().filter(move |&&(_, b)| a != b).map(move |&(j, _)| (i, j))
+        })
+    }).collect::<Vec<(usize, usize)>>();
+
+    pairs.sort();
+    pairs
+}
+
+/// Every pair of distinct IDs, as indices into `ids` in order, at most
+/// `max_edits` apart. Pairs too different in length to be that close are
+/// skipped without being measured, and the common case of IDs one position
+/// apart goes through `near_duplicates`.
+pub fn pairs_within(ids: &[String], distance: Distance, max_edits: usize) -> Vec<(usize, usize)> {
+    if distance == Distance::Hamming && max_edits == 1 {
+        return near_duplicates(ids);
+    }
+
+    let lengths = ids.iter().map(|id| id.chars().count()).collect::<Vec<usize>>();
+    (0..ids.len())
+        .flat_map(|i| (i + 1..ids.len()).map(move |j| (i, j)))
+        .filter(|&(i, j)| lengths[i].abs_diff(lengths[j]) <= max_edits)
+        .filter(|&(i, j)| distance.between(&ids[i], &ids[j]).is_some_and(|d| d >= 1 && d <= max_edits))
+        .collect()
+}
+
+/// The letters shared by the first pair of IDs within `max_edits` of each other.
+pub fn common_letters(ids: &[String], distance: Distance, max_edits: usize) -> Option<String> {
+    pairs_within(ids, distance, max_edits).first().map(|&(i, j)| distance.shared_letters(&ids[i], &ids[j]))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommonLetters {
+    Letters(String),
+    NoMatch
+}
+
+impl fmt::Display for CommonLetters {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CommonLetters::Letters(letters) => write!(f, "{}", letters),
+            CommonLetters::NoMatch => write!(f, "no match")
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub multiplicities: Vec<usize>,
+    pub distance: Distance,
+    pub max_edits: usize
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            multiplicities: vec!(2, 3),
+            distance: Distance::Hamming,
+            max_edits: 1
+        }
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<String>;
+    type Config = Config;
+    type Part1 = Product;
+    type Part2 = CommonLetters;
+
+    fn parse(input_str: &str) -> Result<Vec<String>, ParseError> {
+        Ok(input_str.lines().map(|l| l.trim()).filter(|l| !l.is_empty()).map(|l| l.to_string()).collect())
+    }
+
+    fn part1(ids: &Vec<String>, config: &Config) -> Product {
+        checksum(ids, &config.multiplicities).product
+    }
+
+    fn part2(ids: &Vec<String>, config: &Config) -> CommonLetters {
+        common_letters(ids, config.distance, config.max_edits).map_or(CommonLetters::NoMatch, CommonLetters::Letters)
+    }
+}
+
+#[test]
+fn part_1_test() {
+    assert_eq!(Day2::solve_part1(include_str!("../input/test_input_1.txt"), &Config::default()), Ok(Product::Value(12)));
+}
+
+#[test]
+fn checksum_test() {
+    let ids = Day2::parse(include_str!("../input/test_input_1.txt")).unwrap();
+    assert_eq!(checksum(&ids, &[2, 3, 4]), Checksum { counts: vec!((2, 4), (3, 3), (4, 0)), product: Product::Value(0) });
+    assert_eq!(checksum(&ids, &[1]).product, Product::Value(6));
+
+    let many = vec!("aabbb".to_string(); 300);
+    assert_eq!(checksum(&many, &[2, 3]).product, Product::Value(90000));
+
+    let many = vec!("ab".to_string(); 1 << 16);
+    assert_eq!(checksum(&many, &[1; 4]).product, Product::Overflow);
+    assert_eq!(checksum(&many, &[1; 3]).product, Product::Value(1 << 48));
+    assert_eq!(checksum(&many, &[1; 4]).product.to_string(), "overflow: the checksum is larger than 18446744073709551615");
+}
+
+#[test]
+fn part_2_test() {
+    assert_eq!(Day2::solve_part2(include_str!("../input/test_input_2.txt"), &Config::default()), Ok(CommonLetters::Letters("fgij".to_string())));
+}
+
+#[test]
+fn near_duplicates_test() {
+    let ids = Day2::parse(include_str!("../input/test_input_2.txt")).unwrap();
+    assert_eq!(near_duplicates(&ids), vec!((1, 4)));
+
+    let ids = ["abcd", "abcd", "abce", "xbce", "abc"].iter().map(|s| s.to_string()).collect::<Vec<String>>();
+    let brute_force = (0..ids.len())
+        .flat_map(|i| (i + 1..ids.len()).map(move |j| (i, j)))
+        .filter(|&(i, j)| Distance::Hamming.between(&ids[i], &ids[j]) == Some(1))
+        .collect::<Vec<(usize, usize)>>();
+    assert_eq!(near_duplicates(&ids), brute_force);
+    assert_eq!(brute_force, vec!((0, 2), (1, 2), (2, 3)));
+
+    assert_eq!(Day2::solve_part2("abc\nxyz", &Config::default()), Ok(CommonLetters::NoMatch));
+}
+
+#[test]
+fn pairs_within_test() {
+    let ids = ["abcde", "abde", "bacde", "abcdef", "abcde", "vwxyz"].iter().map(|s| s.to_string()).collect::<Vec<String>>();
+
+    assert_eq!(pairs_within(&ids, Distance::Hamming, 2), vec!((0, 2), (2, 4)));
+    assert_eq!(pairs_within(&ids, Distance::Levenshtein, 1), vec!((0, 1), (0, 3), (1, 4), (3, 4)));
+    assert_eq!(pairs_within(&ids, Distance::Damerau, 1), vec!((0, 1), (0, 2), (0, 3), (1, 4), (2, 4), (3, 4)));
+    assert_eq!(pairs_within(&ids, Distance::Levenshtein, 0), Vec::new());
+
+    let config = Config { distance: Distance::Levenshtein, ..Config::default() };
+    assert_eq!(Day2::solve_part2("abcde\nvwxyz\nabde", &config), Ok(CommonLetters::Letters("abde".to_string())));
+    assert_eq!(Day2::solve_part2("abcde\nvwxyz\nabde", &Config::default()), Ok(CommonLetters::NoMatch));
+}
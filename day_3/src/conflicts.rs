@@ -0,0 +1,150 @@
+use Claim;
+
+/// Two claims sharing some fabric, by claim number with the lower first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Conflict {
+    pub claims: (usize, usize),
+    pub area: usize
+}
+
+/// Which claims overlap which. Claims are swept left to right by their left
+/// edge, so each is only compared with the claims starting before it ends.
+#[derive(Debug, Clone)]
+pub struct ConflictGraph {
+    claim_nums: Vec<usize>,
+    /// For each claim in input order, the claims it overlaps as input
+    /// indices, with the shared area.
+    neighbours: Vec<Vec<(usize, usize)>>,
+    conflicts: Vec<Conflict>
+}
+
+impl ConflictGraph {
+    pub fn new(claims: &[Claim]) -> ConflictGraph {
+        let mut order = (0..claims.len()).collect::<Vec<usize>>();
+        order.sort_by_key(|&i| claims[i].area.p1.0);
+
+        let mut neighbours = vec!(Vec::new(); claims.len());
+        let mut conflicts = Vec::new();
+
+        for (n, &i) in order.iter().enumerate() {
+            let a = &claims[i];
+            for &j in order[n + 1..].iter().take_while(|&&j| claims[j].area.p1.0 < a.area.p2.0) {
+                let b = &claims[j];
+                if let Some(shared) = a.area.intersection(&b.area) {
+                    neighbours[i].push((j, shared.area()));
+                    neighbours[j].push((i, shared.area()));
+                    conflicts.push(Conflict {
+                        claims: (a.claim_num.min(b.claim_num), a.claim_num.max(b.claim_num)),
+                        area: shared.area()
+                    });
+                }
+            }
+        }
+
+        for n in &mut neighbours {
+            n.sort();
+        }
+        conflicts.sort_by_key(|c| c.claims);
+
+        ConflictGraph {
+            claim_nums: claims.iter().map(|c| c.claim_num).collect(),
+            neighbours,
+            conflicts
+        }
+    }
+
+    /// Every overlapping pair, ordered by claim number.
+    pub fn conflicts(&self) -> &[Conflict] {
+        &self.conflicts
+    }
+
+    /// The claims overlapping the claim at `index` in the input, by claim
+    /// number, with the area each shares with it.
+    pub fn conflicts_of(&self, index: usize) -> Vec<(usize, usize)> {
+        self.neighbours[index].iter().map(|&(j, area)| (self.claim_nums[j], area)).collect()
+    }
+
+    /// Claims overlapping no other claim, in input order.
+    pub fn isolated(&self) -> Vec<usize> {
+        self.neighbours.iter()
+            .zip(&self.claim_nums)
+            .filter(|(n, _)| n.is_empty())
+            .map(|(_, &num)| num)
+            .collect()
+    }
+
+    /// Groups of claims linked by overlaps, directly or through other claims
+    /// in the group. Isolated claims are left out; each group is in input
+    /// order, and the groups are ordered by their first claim.
+    pub fn components(&self) -> Vec<Vec<usize>> {
+        let mut seen = vec!(false; self.neighbours.len());
+        let mut components = Vec::new();
+
+        for start in 0..self.neighbours.len() {
+            if seen[start] || self.neighbours[start].is_empty() {
+                continue;
+            }
+
+            seen[start] = true;
+            let mut members = vec!(start);
+            let mut stack = vec!(start);
+            while let Some(i) = stack.pop() {
+                for &(j, _) in &self.neighbours[i] {
+                    if !seen[j] {
+                        seen[j] = true;
+                        members.push(j);
+                        stack.push(j);
+                    }
+                }
+            }
+
+            members.sort();
+            components.push(members.iter().map(|&i| self.claim_nums[i]).collect());
+        }
+
+        components
+    }
+
+    /// The claim overlapping the most others, and how many, taking the first
+    /// in input order on a tie. `None` if no claims overlap at all.
+    pub fn most_conflicted(&self) -> Option<(usize, usize)> {
+        self.neighbours.iter()
+            .zip(&self.claim_nums)
+            .filter(|(n, _)| !n.is_empty())
+            .fold(None, |best: Option<(usize, usize)>, (n, &num)| match best {
+                Some((_, most)) if most >= n.len() => best,
+                _ => Some((num, n.len()))
+            })
+    }
+}
+
+#[test]
+fn conflict_graph_test() {
+    use aoc_common::Solution;
+
+    let claims = ::Day3::parse(include_str!("../input/test_input_1.txt")).unwrap();
+    let graph = ConflictGraph::new(&claims);
+    assert_eq!(graph.conflicts(), &[Conflict { claims: (1, 2), area: 4 }]);
+    assert_eq!(graph.conflicts_of(0), vec!((2, 4)));
+    assert_eq!(graph.isolated(), vec!(3));
+    assert_eq!(graph.components(), vec!(vec!(1, 2)));
+    assert_eq!(graph.most_conflicted(), Some((1, 1)));
+
+    let claims = ::Day3::parse("#1 @ 0,0: 4x4\n#2 @ 3,3: 2x2\n#3 @ 10,0: 2x2\n#4 @ 4,4: 3x1\n#5 @ 11,1: 1x5\n#6 @ 20,20: 1x1\n#7 @ 0,3: 9x1").unwrap();
+    let graph = ConflictGraph::new(&claims);
+    assert_eq!(graph.conflicts(), &[
+        Conflict { claims: (1, 2), area: 1 },
+        Conflict { claims: (1, 7), area: 4 },
+        Conflict { claims: (2, 4), area: 1 },
+        Conflict { claims: (2, 7), area: 2 },
+        Conflict { claims: (3, 5), area: 1 }
+    ]);
+    assert_eq!(graph.isolated(), vec!(6));
+    assert_eq!(graph.components(), vec!(vec!(1, 2, 4, 7), vec!(3, 5)));
+    assert_eq!(graph.most_conflicted(), Some((2, 3)));
+
+    let brute_force = claims.iter().enumerate().flat_map(|(i, a)| claims[i + 1..].iter().filter(move |b| a.intersects(b)).map(move |b| (a.claim_num, b.claim_num)));
+    assert_eq!(graph.conflicts().iter().map(|c| c.claims).collect::<Vec<_>>(), brute_force.collect::<Vec<_>>());
+
+    assert_eq!(ConflictGraph::new(&[]).most_conflicted(), None);
+}
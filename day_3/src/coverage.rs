@@ -0,0 +1,142 @@
+use aoc_common::Point;
+use Claim;
+
+/// How many claims cover each square inch of fabric. The fabric is cut
+/// along every claim edge into blocks that are covered evenly throughout,
+/// and the counts for the blocks are built from a 2D difference array, so
+/// the work depends on the number of claims rather than the fabric's size.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coverage {
+    xs: Vec<usize>,
+    ys: Vec<usize>,
+    /// `counts[j][i]` covers the block from `(xs[i], ys[j])` up to
+    /// `(xs[i + 1], ys[j + 1])`.
+    counts: Vec<Vec<u32>>
+}
+
+fn edges<F: Fn(&Claim) -> (usize, usize)>(claims: &[Claim], edge: F) -> Vec<usize> {
+    let mut all = claims.iter().flat_map(|c| {
+        let (a, b) = edge(c);
+        vec!(a, b)
+    }).collect::<Vec<usize>>();
+
+    all.sort();
+    all.dedup();
+    all
+}
+
+/// The block holding `v`, if it falls between the first and last edge.
+fn block(edges: &[usize], v: usize) -> Option<usize> {
+    match edges.partition_point(|&e| e <= v) {
+        0 => None,
+        i if i == edges.len() => None,
+        i => Some(i - 1)
+    }
+}
+
+impl Coverage {
+    pub fn new(claims: &[Claim]) -> Coverage {
+        let xs = edges(claims, |c| (c.area.p1.0, c.area.p2.0));
+        let ys = edges(claims, |c| (c.area.p1.1, c.area.p2.1));
+        let index = |edges: &[usize], v: usize| edges.binary_search(&v).expect("every claim edge is a block edge");
+
+        let mut diff = vec!(vec!(0i64; xs.len()); ys.len());
+        for c in claims {
+            let (x1, x2) = (index(&xs, c.area.p1.0), index(&xs, c.area.p2.0));
+            let (y1, y2) = (index(&ys, c.area.p1.1), index(&ys, c.area.p2.1));
+            diff[y1][x1] += 1;
+            diff[y1][x2] -= 1;
+            diff[y2][x1] -= 1;
+            diff[y2][x2] += 1;
+        }
+
+        for j in 0..ys.len() {
+            for i in 0..xs.len() {
+                let above = if j > 0 { diff[j - 1][i] } else { 0 };
+                let left = if i > 0 { diff[j][i - 1] } else { 0 };
+                let corner = if i > 0 && j > 0 { diff[j - 1][i - 1] } else { 0 };
+                diff[j][i] += above + left - corner;
+            }
+        }
+
+        let counts = diff.iter()
+            .take(ys.len().saturating_sub(1))
+            .map(|row| row.iter().take(xs.len() - 1).map(|&n| n as u32).collect())
+            .collect();
+
+        Coverage { xs, ys, counts }
+    }
+
+    /// The smallest stretch of fabric holding every claim, from its top left
+    /// corner up to but not including its bottom right, or `None` for no claims.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        match (self.xs.first(), self.xs.last(), self.ys.first(), self.ys.last()) {
+            (Some(&x1), Some(&x2), Some(&y1), Some(&y2)) => Some(((x1, y1), (x2, y2))),
+            _ => None
+        }
+    }
+
+    /// How many claims cover the square inch at `p`.
+    pub fn count(&self, p: Point) -> u32 {
+        match (block(&self.xs, p.0), block(&self.ys, p.1)) {
+            (Some(i), Some(j)) => self.counts[j][i],
+            _ => 0
+        }
+    }
+
+    /// The area covered by more claims than `n`.
+    pub fn area_over(&self, n: u32) -> usize {
+        self.counts.iter().enumerate().map(|(j, row)| {
+            let height = self.ys[j + 1] - self.ys[j];
+            row.iter().enumerate()
+                .filter(|&(_, &count)| count > n)
+                .map(|(i, _)| (self.xs[i + 1] - self.xs[i]) * height)
+                .sum::<usize>()
+        }).sum()
+    }
+
+    /// The area covered by two or more claims.
+    pub fn overlap_area(&self) -> usize {
+        self.area_over(1)
+    }
+
+    /// Every square inch within the bounds and how many claims cover it,
+    /// row by row.
+    pub fn cells(&self) -> impl Iterator<Item = (Point, u32)> + '_ {
+        let ((x1, y1), (x2, y2)) = self.bounds().unwrap_or(((0, 0), (0, 0)));
+        (y1..y2).flat_map(move |y| (x1..x2).map(move |x| ((x, y), self.count((x, y)))))
+    }
+}
+
+#[test]
+fn coverage_test() {
+    use aoc_common::Solution;
+
+    let claims = ::Day3::parse(include_str!("../input/test_input_1.txt")).unwrap();
+    let coverage = Coverage::new(&claims);
+
+    assert_eq!(coverage.bounds(), Some(((1, 1), (7, 7))));
+    assert_eq!(coverage.overlap_area(), 4);
+    assert_eq!(coverage.area_over(0), 32);
+    assert_eq!((coverage.count((3, 3)), coverage.count((1, 3)), coverage.count((5, 5)), coverage.count((0, 0))), (2, 1, 1, 0));
+    assert_eq!(coverage.cells().count(), 36);
+    assert_eq!(coverage.cells().filter(|&(_, n)| n > 1).count(), 4);
+
+    let empty = Coverage::new(&[]);
+    assert_eq!((empty.bounds(), empty.overlap_area(), empty.cells().count()), (None, 0, 0));
+}
+
+#[test]
+fn coverage_brute_force_test() {
+    use aoc_common::Solution;
+
+    let claims = ::Day3::parse("#1 @ 0,0: 5x3\n#2 @ 2,1: 2x6\n#3 @ 3,2: 4x1\n#4 @ 6,6: 0x3\n#5 @ 1,0: 3x3").unwrap();
+    let coverage = Coverage::new(&claims);
+
+    for y in 0..10 {
+        for x in 0..10 {
+            let covering = claims.iter().filter(|c| c.contains_point((x, y))).count() as u32;
+            assert_eq!(coverage.count((x, y)), covering, "at {},{}", x, y);
+        }
+    }
+}
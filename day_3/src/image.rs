@@ -0,0 +1,156 @@
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use aoc_common::{Point, Rect};
+use conflicts::ConflictGraph;
+use coverage::Coverage;
+use Claim;
+
+/// The colour of uncovered fabric, a single claim, and claims that overlap
+/// no other. Overlapping cells shade from yellow to red as coverage rises.
+const EMPTY: [u8; 3] = [16, 16, 16];
+const SINGLE: [u8; 3] = [48, 96, 176];
+const ISOLATED: [u8; 3] = [64, 200, 96];
+
+/// The fabric from `(0, 0)` to the far edges of the claims, with each
+/// square inch's coverage and whether it lies in an isolated claim.
+struct Fabric {
+    size: Point,
+    coverage: Coverage,
+    isolated: Vec<Rect>,
+    max: u32
+}
+
+impl Fabric {
+    fn new(claims: &[Claim]) -> Fabric {
+        let coverage = Coverage::new(claims);
+        let isolated_nums = ConflictGraph::new(claims).isolated().into_iter().collect::<HashSet<usize>>();
+
+        Fabric {
+            size: coverage.bounds().map_or((0, 0), |(_, far)| far),
+            max: coverage.cells().map(|(_, n)| n).max().unwrap_or(0),
+            isolated: claims.iter().filter(|c| isolated_nums.contains(&c.claim_num)).map(|c| c.area).collect(),
+            coverage
+        }
+    }
+
+    /// Each square inch as `(coverage, isolated)`, row by row.
+    fn cells(&self) -> impl Iterator<Item = (u32, bool)> + '_ {
+        let (width, height) = self.size;
+        (0..height).flat_map(move |y| (0..width).map(move |x| {
+            (self.coverage.count((x, y)), self.isolated.iter().any(|r| r.contains_point((x, y))))
+        }))
+    }
+}
+
+fn colour(count: u32, max: u32, isolated: bool) -> [u8; 3] {
+    match count {
+        _ if isolated => ISOLATED,
+        0 => EMPTY,
+        1 => SINGLE,
+        n => {
+            let heat = if max > 2 { (n - 2) * 255 / (max - 2) } else { 0 };
+            [255, 200 - (heat * 200 / 255) as u8, 0]
+        }
+    }
+}
+
+/// A grey level that gets brighter with coverage, with isolated claims
+/// drawn in full white above every covered shade.
+fn grey(count: u32, max: u32, isolated: bool) -> u8 {
+    match count {
+        _ if isolated => 255,
+        0 => 0,
+        n => (64 + (n - 1) * 160 / max.max(1)) as u8
+    }
+}
+
+/// Writes the fabric as a binary colour PPM image, one pixel per square inch.
+pub fn write_ppm<W: Write>(claims: &[Claim], mut output: W) -> io::Result<()> {
+    let fabric = Fabric::new(claims);
+    write!(output, "P6\n{} {}\n255\n", fabric.size.0, fabric.size.1)?;
+
+    let pixels = fabric.cells().flat_map(|(n, iso)| colour(n, fabric.max, iso).to_vec()).collect::<Vec<u8>>();
+    output.write_all(&pixels)?;
+    output.flush()
+}
+
+/// Writes the fabric as a binary greyscale PGM image, one pixel per square inch.
+pub fn write_pgm<W: Write>(claims: &[Claim], mut output: W) -> io::Result<()> {
+    let fabric = Fabric::new(claims);
+    write!(output, "P5\n{} {}\n255\n", fabric.size.0, fabric.size.1)?;
+
+    let pixels = fabric.cells().map(|(n, iso)| grey(n, fabric.max, iso)).collect::<Vec<u8>>();
+    output.write_all(&pixels)?;
+    output.flush()
+}
+
+/// Writes the fabric to the file at `path`, as a greyscale PGM if it ends
+/// in `.pgm` and a colour PPM otherwise.
+pub fn write_image(claims: &[Claim], path: &Path) -> io::Result<()> {
+    let output = BufWriter::new(File::create(path)?);
+    if path.extension().is_some_and(|e| e == "pgm") {
+        write_pgm(claims, output)
+    } else {
+        write_ppm(claims, output)
+    }
+}
+
+#[test]
+fn write_ppm_test() {
+    use aoc_common::Solution;
+
+    let claims = ::Day3::parse(include_str!("../input/test_input_1.txt")).unwrap();
+    let mut image = Vec::new();
+    write_ppm(&claims, &mut image).unwrap();
+
+    let header = b"P6\n7 7\n255\n";
+    assert_eq!(&image[..header.len()], header);
+    let pixels = image[header.len()..].chunks(3).collect::<Vec<&[u8]>>();
+    assert_eq!(pixels.len(), 49);
+
+    let at = |x: usize, y: usize| pixels[y * 7 + x];
+    assert_eq!(at(0, 0), EMPTY);
+    assert_eq!(at(1, 3), SINGLE);
+    assert_eq!(at(3, 3), [255, 200, 0]);
+    assert_eq!(at(5, 5), ISOLATED);
+}
+
+#[test]
+fn write_pgm_test() {
+    use aoc_common::Solution;
+
+    let claims = ::Day3::parse(include_str!("../input/test_input_1.txt")).unwrap();
+    let mut image = Vec::new();
+    write_pgm(&claims, &mut image).unwrap();
+
+    let header = b"P5\n7 7\n255\n";
+    assert_eq!(&image[..header.len()], header);
+    let pixels = &image[header.len()..];
+    assert_eq!(pixels.len(), 49);
+    assert_eq!((pixels[0], pixels[3 * 7 + 1], pixels[3 * 7 + 3], pixels[5 * 7 + 5]), (0, 64, 144, 255));
+
+    let mut empty = Vec::new();
+    write_pgm(&[], &mut empty).unwrap();
+    assert_eq!(empty, b"P5\n0 0\n255\n");
+}
+
+#[test]
+fn flush_error_test() {
+    struct FailingFlush(Vec<u8>);
+
+    impl Write for FailingFlush {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Err(io::Error::other("disk full"))
+        }
+    }
+
+    assert_eq!(write_ppm(&[], FailingFlush(Vec::new())).unwrap_err().to_string(), "disk full");
+    assert_eq!(write_pgm(&[], FailingFlush(Vec::new())).unwrap_err().to_string(), "disk full");
+}
@@ -0,0 +1,119 @@
+extern crate aoc_common;
+
+pub mod conflicts;
+pub mod coverage;
+pub mod image;
+
+use std::fmt;
+use aoc_common::{parse_field, parse_lines_with, parse_pair, ParseError, Point, Rect, Solution};
+use conflicts::ConflictGraph;
+use coverage::Coverage;
+
+#[derive(Debug)]
+pub struct Claim {
+    claim_num : usize,
+    area : Rect
+}
+
+impl Claim {
+    fn new(claim_desc : &str) -> Result<Claim, ParseError> {
+        let split: Vec<&str> = claim_desc.splitn(4, ['#', '@', ':']).map(|s| s.trim()).collect();
+        if split.len() != 4 || !split[0].is_empty() {
+            return Err(ParseError::in_line(claim_desc, claim_desc, "a claim like '#1 @ 1,3: 4x4'"));
+        }
+
+        let coords: Point = parse_pair(split[2], ',').map_err(|e| e.within(claim_desc, split[2]))?;
+        let size: Point = parse_pair(split[3], 'x').map_err(|e| e.within(claim_desc, split[3]))?;
+
+        Ok(Claim {
+            claim_num : parse_field(claim_desc, split[1], "a claim number")?,
+            area : Rect::new(coords, size)
+        })
+    }
+
+    pub fn contains_point(&self, p : Point) -> bool {
+        self.area.contains_point(p)
+    }
+
+    pub fn intersects(&self, c: &Claim) -> bool {
+        self.area.intersects(&c.area)
+    }
+}
+
+fn get_num_overlaps(claims: &[Claim]) -> usize {
+    Coverage::new(claims).overlap_area()
+}
+
+/// The first claim, in input order, that overlaps no other.
+fn find_isolated_claim(claims: &[Claim]) -> Option<usize> {
+    ConflictGraph::new(claims).isolated().first().cloned()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IsolatedClaim {
+    Claim(usize),
+    NoneIsolated
+}
+
+impl fmt::Display for IsolatedClaim {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IsolatedClaim::Claim(num) => write!(f, "{}", num),
+            IsolatedClaim::NoneIsolated => write!(f, "no isolated claim")
+        }
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Claim>;
+    type Config = ();
+    type Part1 = usize;
+    type Part2 = IsolatedClaim;
+
+    fn parse(input_str: &str) -> Result<Vec<Claim>, ParseError> {
+        parse_lines_with(input_str, Claim::new)
+    }
+
+    fn part1(claims: &Vec<Claim>, _: &()) -> usize {
+        get_num_overlaps(claims)
+    }
+
+    fn part2(claims: &Vec<Claim>, _: &()) -> IsolatedClaim {
+        find_isolated_claim(claims).map_or(IsolatedClaim::NoneIsolated, IsolatedClaim::Claim)
+    }
+}
+
+#[test]
+fn part_1_test() {
+    assert_eq!(Day3::solve_part1(include_str!("../input/test_input_1.txt"), &()), Ok(4));
+}
+
+#[test]
+fn part_2_test() {
+    assert_eq!(Day3::solve_part2(include_str!("../input/test_input_1.txt"), &()), Ok(IsolatedClaim::Claim(3)));
+    assert_eq!(Day3::solve_part2("#1 @ 1,3: 4x4\n#2 @ 3,1: 4x4", &()), Ok(IsolatedClaim::NoneIsolated));
+}
+
+#[test]
+fn parse_error_test() {
+    let err = Day3::parse("#1 @ 1,3: 4x4\n#2 @ 3,y: 4x4").unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (2, Some(8), "y"));
+
+    let err = Day3::parse("#1 @ 1,3: 4x4\n#2 @ 3,1: 4x4\n#3 5,5 2x2").unwrap_err();
+    assert_eq!((err.line, err.column), (3, Some(1)));
+}
+
+#[test]
+fn claim_geometry_test() {
+    let claims = Day3::parse(include_str!("../input/test_input_1.txt")).unwrap();
+    assert!(claims[0].contains_point((1, 3)) && claims[0].contains_point((4, 6)));
+    assert!(!claims[0].contains_point((5, 6)) && !claims[0].contains_point((4, 7)));
+
+    for a in &claims {
+        for b in &claims {
+            assert_eq!(a.intersects(b), a.area.cells().any(|p| b.contains_point(p)));
+        }
+    }
+}
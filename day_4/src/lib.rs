@@ -0,0 +1,192 @@
+extern crate aoc_common;
+extern crate chrono;
+
+use std::collections::HashMap;
+use std::fmt;
+use aoc_common::{parse_field, parse_lines_with, ParseError, Solution};
+use chrono::{NaiveDateTime};
+
+#[derive(Debug)]
+enum Event {
+    None,
+    Begin(i32),
+    Sleep(NaiveDateTime),
+    Wake(NaiveDateTime)
+}
+
+/*
+[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:25] wakes up
+[1518-11-01 00:30] falls asleep
+[1518-11-01 00:55] wakes up
+[1518-11-01 23:58] Guard #99 begins shift
+[1518-11-02 00:40] falls asleep
+[1518-11-02 00:50] wakes up
+[1518-11-03 00:05] Guard #10 begins shift
+[1518-11-03 00:24] falls asleep
+[1518-11-03 00:29] wakes up
+[1518-11-04 00:02] Guard #99 begins shift
+[1518-11-04 00:36] falls asleep
+[1518-11-04 00:46] wakes up
+[1518-11-05 00:03] Guard #99 begins shift
+[1518-11-05 00:45] falls asleep
+[1518-11-05 00:55] wakes up
+*/
+
+fn parse_time(time_str: &str) -> Result<NaiveDateTime, ParseError> {
+    NaiveDateTime::parse_from_str(time_str, "%F %R").map_err(|_| {
+        ParseError::in_line(time_str, time_str, "a timestamp like '1518-11-01 00:00'")
+    })
+}
+
+fn parse_event(event_str: &str, at_time: NaiveDateTime) -> Result<Event, ParseError> {
+    match event_str.chars().next() {
+        Some('G') => {
+            let guard = event_str.split_whitespace().nth(1).unwrap_or(event_str);
+            Ok(Event::Begin(parse_field(event_str, guard.trim_start_matches('#'), "a guard number")?))
+        },
+        Some('f') => Ok(Event::Sleep(at_time)),
+        Some('w') => Ok(Event::Wake(at_time)),
+        _ => Err(ParseError::in_line(event_str, event_str, "'Guard #<id> begins shift', 'falls asleep' or 'wakes up'"))
+    }
+}
+
+fn parse_entry(line: &str) -> Result<(NaiveDateTime, Event), ParseError> {
+    let splits: Vec<&str> = line.splitn(3, ['[', ']']).collect();
+    if splits.len() != 3 || !splits[0].is_empty() {
+        return Err(ParseError::in_line(line, line, "an entry like '[1518-11-01 00:00] falls asleep'"));
+    }
+
+    let timestamp = parse_time(splits[1]).map_err(|e| e.within(line, splits[1]))?;
+    let event_str = splits[2].trim();
+    let event = parse_event(event_str, timestamp).map_err(|e| e.within(line, event_str))?;
+
+    Ok((timestamp, event))
+}
+
+pub type SleepMap = HashMap<i64, i32>;
+pub type GuardSleepMap = HashMap<i32, SleepMap>;
+
+fn update_sleep_map(from: &NaiveDateTime, to: &NaiveDateTime, sleep_map : &mut SleepMap) {
+    let start_min = (*from - from.date().and_hms_opt(0,0,0).unwrap()).num_minutes();
+    let end_min = start_min + (*to - *from).num_minutes();
+    for min in start_min..end_min {
+        *sleep_map.entry(min).or_insert(0) += 1;
+    }
+}
+
+fn generate_guard_sleep_map(input_str: &str) -> Result<GuardSleepMap, ParseError> {
+    let mut entries = parse_lines_with(input_str, parse_entry)?;
+
+    entries.sort_unstable_by_key(|e| e.0);
+
+    let mut current_guard = 0;
+    let mut prev_event = Event::None;
+    let mut guard_map = HashMap::new();
+
+    for entry in entries {
+        match entry.1 {
+            Event::Begin(guard_num) => {
+                //Start a new shift
+                current_guard = guard_num;
+            },
+            Event::Wake(wake_time) => {
+                if let Event::Sleep(sleep_time) = prev_event {
+                    update_sleep_map(&sleep_time, &wake_time, guard_map.entry(current_guard).or_default());
+                }
+            },
+            _ => {}
+        }
+
+        prev_event = entry.1;
+    }
+
+    Ok(guard_map)
+}
+
+/// The guard number times the minute they're asleep most, for the guard
+/// picked by `sleepiest`, or `None` if no guard ever slept.
+fn guard_times_minute<K: Ord, F: Fn(&SleepMap) -> K>(guard_map: &GuardSleepMap, sleepiest: F) -> Option<i32> {
+    let sleepiest_guard = guard_map.iter().filter(|(_,v)| !v.is_empty()).max_by_key(|(_,v)| sleepiest(v))?;
+    let sleepiest_minute = sleepiest_guard.1.iter().max_by_key(|(_,&v)| v)?;
+
+    Some(*sleepiest_guard.0 * (*sleepiest_minute.0 as i32))
+}
+
+fn most_asleep_total(guard_map: &GuardSleepMap) -> Option<i32> {
+    guard_times_minute(guard_map, |v| v.values().sum::<i32>())
+}
+
+fn most_asleep_on_one_minute(guard_map: &GuardSleepMap) -> Option<i32> {
+    guard_times_minute(guard_map, |v| v.values().max().cloned())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SleepiestGuard {
+    Answer(i32),
+    NoneAsleep
+}
+
+impl fmt::Display for SleepiestGuard {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SleepiestGuard::Answer(n) => write!(f, "{}", n),
+            SleepiestGuard::NoneAsleep => write!(f, "no guard fell asleep")
+        }
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = GuardSleepMap;
+    type Config = ();
+    type Part1 = SleepiestGuard;
+    type Part2 = SleepiestGuard;
+
+    fn parse(input_str: &str) -> Result<GuardSleepMap, ParseError> {
+        generate_guard_sleep_map(input_str)
+    }
+
+    fn part1(guard_map: &GuardSleepMap, _: &()) -> SleepiestGuard {
+        most_asleep_total(guard_map).map_or(SleepiestGuard::NoneAsleep, SleepiestGuard::Answer)
+    }
+
+    fn part2(guard_map: &GuardSleepMap, _: &()) -> SleepiestGuard {
+        most_asleep_on_one_minute(guard_map).map_or(SleepiestGuard::NoneAsleep, SleepiestGuard::Answer)
+    }
+}
+
+#[test]
+fn part_1_test() {
+    assert_eq!(Day4::solve_part1(include_str!("../input/test_input_1.txt"), &()), Ok(SleepiestGuard::Answer(240)));
+}
+
+#[test]
+fn part_2_test() {
+    assert_eq!(Day4::solve_part2(include_str!("../input/test_input_1.txt"), &()), Ok(SleepiestGuard::Answer(4455)));
+}
+
+#[test]
+fn none_asleep_test() {
+    let awake = "[1518-11-01 00:00] Guard #10 begins shift\n[1518-11-02 00:00] Guard #99 begins shift";
+    assert_eq!(Day4::solve_part1(awake, &()), Ok(SleepiestGuard::NoneAsleep));
+    assert_eq!(Day4::solve_part2(awake, &()), Ok(SleepiestGuard::NoneAsleep));
+    assert_eq!(Day4::solve_part1("", &()).unwrap().to_string(), "no guard fell asleep");
+
+    let dozed = "[1518-11-01 00:00] Guard #10 begins shift\n[1518-11-01 00:05] falls asleep\n[1518-11-01 00:05] wakes up";
+    assert_eq!(Day4::solve_part2(dozed, &()), Ok(SleepiestGuard::NoneAsleep));
+}
+
+#[test]
+fn parse_error_test() {
+    let err = Day4::parse("[1518-11-01 00:00] Guard #10 begins shift\n[1518-13-01 00:05] falls asleep").unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (2, Some(2), "1518-13-01 00:05"));
+
+    let err = Day4::parse("[1518-11-01 00:00] Guard #x10 begins shift").unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (1, Some(27), "x10"));
+
+    let err = Day4::parse("[1518-11-01 00:00] Guard #10 begins shift\n[1518-11-01 00:05] dozes off").unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (2, Some(20), "dozes off"));
+}
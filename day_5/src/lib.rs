@@ -0,0 +1,70 @@
+extern crate aoc_common;
+
+use std::collections::HashSet;
+use aoc_common::{ParseError, Solution};
+
+fn is_reaction(a: &char, b: &char) -> bool {
+    a.eq_ignore_ascii_case(b) && a.is_ascii_uppercase() != b.is_ascii_uppercase()
+}
+
+fn react(polymer: Vec<char>) -> usize {
+    let mut reduced : Vec<char> = Vec::new();
+    for p in polymer {
+        if reduced.last().map(|&c| is_reaction(&p, &c)).unwrap_or(false) {
+            reduced.pop();
+        } else {
+            reduced.push(p);
+        }
+    }
+
+    reduced.len()
+}
+
+fn shortest_without_one_unit(polymer: &[char]) -> usize {
+    polymer.iter().fold(HashSet::new(), |mut set, c| {
+        set.insert(c.to_ascii_lowercase());
+        set
+    }).iter().map(|u| {
+        react(polymer.iter().cloned().filter(|c| {
+            !c.eq_ignore_ascii_case(u)
+        }).collect::<Vec<char>>())
+    }).min().unwrap()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vec<char>;
+    type Config = ();
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input_str: &str) -> Result<Vec<char>, ParseError> {
+        Ok(input_str.trim().chars().collect())
+    }
+
+    fn part1(polymer: &Vec<char>, _: &()) -> usize {
+        react(polymer.clone())
+    }
+
+    fn part2(polymer: &Vec<char>, _: &()) -> usize {
+        shortest_without_one_unit(polymer)
+    }
+}
+
+#[test]
+fn test_reaction() {
+    assert!(is_reaction(&'a',&'A'));
+    assert!(!is_reaction(&'a',&'b'));
+    assert!(!is_reaction(&'a',&'a'));
+}
+
+#[test]
+fn part_1_test() {
+    assert_eq!(Day5::solve_part1(include_str!("../input/test_input_1.txt"), &()), Ok(10));
+}
+
+#[test]
+fn part_2_test() {
+    assert_eq!(Day5::solve_part2(include_str!("../input/test_input_1.txt"), &()), Ok(4));
+}
\ No newline at end of file
@@ -1,55 +1,10 @@
-use std::collections::HashSet;
+extern crate aoc_common;
+extern crate day_5;
 
-fn is_reaction(a: &char, b: &char) -> bool {
-    a.eq_ignore_ascii_case(b) && a.is_ascii_uppercase() != b.is_ascii_uppercase()
-}
-
-fn react(polymer: Vec<char>) -> usize {
-    let mut reduced : Vec<char> = Vec::new();
-    for p in polymer {
-        if reduced.last().map(|&c| is_reaction(&p, &c)).unwrap_or(false) {
-            reduced.pop();
-        } else {
-            reduced.push(p.clone());
-        }
-    }
-
-    reduced.len()
-}
+use aoc_common::{input, solution, ParseError};
+use day_5::Day5;
 
-fn part_1_solve(input_str: &str) -> usize {
-    react(input_str.chars().collect::<Vec<char>>())
+fn main() -> Result<(), ParseError> {
+    let input_str = input::load_from_args(5, concat!(env!("CARGO_MANIFEST_DIR"), "/.."));
+    solution::print_solution::<Day5>(&input_str, &())
 }
-
-fn part_2_solve(input_str: &str) -> usize {
-    input_str.chars().fold(HashSet::new(), |mut set, c| {
-        set.insert(c.to_ascii_lowercase());
-        set
-    }).iter().map(|u| {
-        react(input_str.chars().filter(|c| {
-            !c.eq_ignore_ascii_case(u)
-        }).collect::<Vec<char>>())
-    }).min().unwrap()
-}
-
-fn main() {
-    println!("Part 1: {}", part_1_solve(include_str!("../input/input.txt")));
-    println!("Part 2: {}", part_2_solve(include_str!("../input/input.txt")));
-}
-
-#[test]
-fn test_reaction() {
-    assert!(is_reaction(&'a',&'A'));
-    assert!(!is_reaction(&'a',&'b'));
-    assert!(!is_reaction(&'a',&'a'));
-}
-
-#[test]
-fn part_1_test() {
-    assert_eq!(part_1_solve(include_str!("../input/test_input_1.txt")), 10);
-}
-
-#[test]
-fn part_2_test() {
-    assert_eq!(part_2_solve(include_str!("../input/test_input_1.txt")), 4);
-}
\ No newline at end of file
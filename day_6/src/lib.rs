@@ -0,0 +1,96 @@
+extern crate aoc_common;
+
+use aoc_common::{bounding_box, create_grid, manhattan_dist, parse_lines_with, parse_pair, ParseError, Point, Solution};
+
+fn total_dist(p: &Point, points: &[Point]) -> usize {
+    points.iter().map(|t| manhattan_dist(p,t)).sum()
+}
+
+fn get_points(input_str: &str) -> Result<Vec<Point>, ParseError> {
+    parse_lines_with(input_str, |l| parse_pair(l, ','))
+}
+
+fn has_finite_boundary(point: &Point, points: &[Point]) -> bool {
+    points.iter().any(|(x,_)| x < &point.0) && points.iter().any(|(x,_)| x > &point.0) &&
+    points.iter().any(|(_,y)| y < &point.1) && points.iter().any(|(_,y)| y > &point.1)
+}
+
+fn get_closest_point(p: &Point, points: &[Point]) -> Option<Point> {
+    let distances = points.iter().map(|t| (t, manhattan_dist(p, t))).collect::<Vec<(&Point, usize)>>();
+    let min_dist = distances.iter().min_by_key(|(_,d)| d).unwrap();
+    if distances.iter().filter(|(_,d)| d == &min_dist.1).count() > 1 {
+        None
+    } else  {
+        Some(*min_dist.0)
+    }
+}
+
+fn largest_finite_area(points: &[Point]) -> usize {
+    let (min, max) = bounding_box(points.iter().cloned()).unwrap();
+
+    let filtered = points.iter().filter(|&p| has_finite_boundary(p, points)).collect::<Vec<&Point>>();
+
+    filtered.into_iter().map(|f| {
+        create_grid(min, max).into_iter().filter(|p| {
+            match get_closest_point(p, points) {
+                Some(x) => x == *f,
+                None => false
+            }
+        }).count()
+    }).max().unwrap()
+}
+
+fn safe_region_size(points: &[Point], max_dist: usize) -> usize {
+    let (min, max) = bounding_box(points.iter().cloned()).unwrap();
+
+    create_grid(min, max).into_iter().filter(|p| {
+        total_dist(p, points) < max_dist
+    }).count()
+}
+
+pub struct Config {
+    pub max_dist: usize
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config { max_dist: 10000 }
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<Point>;
+    type Config = Config;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input_str: &str) -> Result<Vec<Point>, ParseError> {
+        get_points(input_str)
+    }
+
+    fn part1(points: &Vec<Point>, _: &Config) -> usize {
+        largest_finite_area(points)
+    }
+
+    fn part2(points: &Vec<Point>, config: &Config) -> usize {
+        safe_region_size(points, config.max_dist)
+    }
+}
+
+#[test]
+fn part_1_test() {
+    assert_eq!(Day6::solve_part1(include_str!("../input/test_input_1.txt"), &Config::default()), Ok(17));
+}
+
+#[test]
+fn part_2_test() {
+    assert_eq!(Day6::solve_part2(include_str!("../input/test_input_1.txt"), &Config { max_dist: 32 }), Ok(16));
+}
+
+#[test]
+fn parse_error_test() {
+    let err = Day6::parse("1, 1\n1, 6\n8 3").unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (3, Some(1), "8 3"));
+}
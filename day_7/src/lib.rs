@@ -0,0 +1,265 @@
+extern crate aoc_common;
+
+use std::cmp;
+use std::collections::BTreeMap;
+use aoc_common::{parse_lines_with, ParseError, Solution};
+
+pub type PreReqVec = Vec<char>;
+pub type NodeMap = BTreeMap<char, PreReqVec>;
+
+#[derive(Debug, Clone)]
+struct Job {
+    node: char,
+    end_time: u32
+}
+
+#[derive(Debug, Clone)]
+struct Worker {
+    queue: Vec<Job>
+}
+
+impl Worker {
+    fn get_node_end_time(&self, node: char) -> Option<u32> {
+        self.queue.iter().find(|j| j.node == node).map(|j| j.end_time)
+    }
+
+    fn get_earliest_start_time(&self) -> u32 {
+        self.queue.last().map(|j| j.end_time).unwrap_or(0)
+    }
+
+    fn schedule(&mut self, n: char, not_before: u32, duration: u32) {
+        //println!("\t\tQueue => {:?}", self.queue);
+        let start_at = cmp::max(not_before, self.get_earliest_start_time());
+        let d = get_node_time(n, duration);
+        let e = start_at + d;
+        //println!("\t\tScheduling node {} to start at {} with duration {}", n, start_at, d);
+        self.queue.push(Job { 
+            node: n, 
+            end_time: e
+        });
+        //println!("\t\tQueue => {:?}", self.queue);
+    }
+
+    fn bid_node(&self, start_time: u32) -> u32 {
+        let e = self.get_earliest_start_time();
+        cmp::max(e, start_time) - start_time
+    }
+
+    fn node_at_time(&self, time: u32, duration: u32) -> Option<char> {
+        self.queue.iter().find(|j| {
+            time >= (j.end_time - get_node_time(j.node, duration)) && time < j.end_time
+        }).map(|j| j.node)
+    }
+}
+
+struct ElfPool {
+    workers : Vec<Worker>,
+    duration : u32, 
+    nodes : NodeMap
+}
+
+impl ElfPool {
+    fn new(num_workers: u32, dur: u32, map: &NodeMap) -> ElfPool {
+        ElfPool { 
+            workers : vec!(Worker { queue: vec!() } ; num_workers as usize), 
+            duration : dur,
+            nodes: map.to_owned()
+        }
+    }
+
+    fn get_node_end_time(&self, node: char) -> Option<u32> {
+        self.workers.iter().find_map(|w| w.get_node_end_time(node))
+    }
+
+    fn is_node_queued(&self, node: char) -> bool {
+        self.workers.iter().any(|w| w.get_node_end_time(node).is_some())
+    }
+
+    fn can_schedule(&mut self, node: char) -> bool {
+         !self.is_node_queued(node) &&
+         self.nodes.get(&node).unwrap_or(&PreReqVec::new()).iter().all(|p| self.is_node_queued(*p))
+    }
+
+    fn schedule(&mut self, node: char) {
+        //println!("Attempting to schedule node {}", node);
+        let start_time = self.nodes.get(&node).unwrap_or(&PreReqVec::new()).iter().filter_map(|n| {
+            self.get_node_end_time(*n)
+        }).max().unwrap_or(0);
+       // println!("\tEarliest possible start time is {}", start_time);
+        (*self.workers.iter_mut().min_by_key(|w| w.bid_node(start_time)).unwrap()).schedule(node, start_time, self.duration); 
+    }
+
+    fn resolve(&self) -> u32 {
+        self.workers.iter().map(|w| w.get_earliest_start_time()).max().unwrap()
+    }
+
+    #[allow(dead_code)]
+    fn print(&self) {
+        for x in 0..=self.resolve() {
+            print!("{:04}", x);
+            for job in self.workers.iter().map(|w| w.node_at_time(x, self.duration)) {
+                print!("\t{}", job.unwrap_or('.'));
+            }
+            println!();
+        }
+    }
+}
+
+fn get_node_time(node: char, base_time: u32) -> u32 {
+    assert!(node.is_alphabetic() && node.is_uppercase());
+    (node as u8 - 64) as u32 + base_time
+}
+
+fn parse_step(line: &str) -> Result<(char, char), ParseError> {
+    if !line.starts_with("Step ") || line.get(6..36) != Some(" must be finished before step ") {
+        return Err(ParseError::in_line(line, line, "a step like 'Step C must be finished before step A can begin.'"));
+    }
+
+    let step_at = |idx: usize| {
+        let step = line.get(idx..idx+1).unwrap_or(&line[line.len()..]);
+        match step.chars().next() {
+            Some(c) if c.is_ascii_uppercase() => Ok(c),
+            _ => Err(ParseError::in_line(line, step, "an uppercase step letter"))
+        }
+    };
+
+    Ok((step_at(5)?, step_at(36)?))
+}
+
+/// Whether `node` has to wait for `pre_req`, directly or through other steps.
+fn depends_on(nodes: &NodeMap, node: char, pre_req: char) -> bool {
+    let mut stack = vec!(node);
+    let mut seen = vec!();
+
+    while let Some(n) = stack.pop() {
+        if n == pre_req {
+            return true;
+        }
+        if !seen.contains(&n) {
+            seen.push(n);
+            stack.extend(nodes.get(&n).unwrap_or(&PreReqVec::new()));
+        }
+    }
+
+    false
+}
+
+fn build_node_map(input_str: &str) -> Result<NodeMap, ParseError> {
+    let nodes = parse_lines_with(input_str, parse_step)?.into_iter().fold(NodeMap::new(), |mut map, (pre_req, node)| {
+        map.entry(pre_req).or_default();
+        map.entry(node).or_default().push(pre_req);
+        map
+    });
+
+    // A step that has to wait for itself can never start, so report the
+    // first line that closes such a loop.
+    parse_lines_with(input_str, |l| {
+        let (pre_req, node) = parse_step(l)?;
+        match depends_on(&nodes, pre_req, node) {
+            true => Err(ParseError::in_line(l, l, &format!("a step order with no loops, but step {} already waits for step {}", pre_req, node))),
+            false => Ok(())
+        }
+    })?;
+
+    Ok(nodes)
+}
+
+fn is_node_available(node: &char, nodes: &NodeMap, visited: &[char]) -> bool {
+    !visited.contains(node) && nodes.get(node).unwrap().iter().all(|n| visited.contains(n))
+}
+
+fn traverse_nodes(nodes: &NodeMap) -> String {
+    let mut visited : Vec<char> = vec!();
+    let mut keys = nodes.keys().cloned().collect::<Vec<char>>();
+
+    while !keys.is_empty() {
+        let found_idx = keys.iter().enumerate().find(|x| is_node_available(x.1, nodes, &visited)).unwrap().0;
+        visited.push(keys.remove(found_idx));
+    }
+
+    visited.iter().collect::<String>()
+}
+
+fn traverse_nodes_parallel(nodes: &NodeMap, num_workers: u32, duration: u32) -> u32 {
+    let mut pool = ElfPool::new(num_workers, duration, nodes);
+
+    while nodes.keys().any(|n| !pool.is_node_queued(*n)) {
+        let schedule_keys = nodes.keys().cloned().filter(|k| pool.can_schedule(*k)).collect::<Vec<char>>();
+        //println!("== Schedule Pass for {:?} ==", schedule_keys);
+        for n in schedule_keys {
+            pool.schedule(n);
+        }
+        //println!("=================================");
+    }
+
+    //pool.print();
+
+    pool.resolve()
+}
+
+pub struct Config {
+    pub workers: u32,
+    pub base_duration: u32
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config { workers: 5, base_duration: 60 }
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = NodeMap;
+    type Config = Config;
+    type Part1 = String;
+    type Part2 = u32;
+
+    fn parse(input_str: &str) -> Result<NodeMap, ParseError> {
+        build_node_map(input_str)
+    }
+
+    fn part1(nodes: &NodeMap, _: &Config) -> String {
+        traverse_nodes(nodes)
+    }
+
+    fn part2(nodes: &NodeMap, config: &Config) -> u32 {
+        traverse_nodes_parallel(nodes, config.workers, config.base_duration)
+    }
+}
+
+#[test]
+fn part_1_test() {
+    assert_eq!(Day7::solve_part1(include_str!("../input/test_input_1.txt"), &Config::default()), Ok("CABDFE".to_string()));
+}
+
+#[test]
+fn part_2_test() {
+    let config = Config { workers: 2, base_duration: 0 };
+    assert_eq!(Day7::solve_part2(include_str!("../input/test_input_1.txt"), &config), Ok(15));
+    assert_eq!(get_node_time('A', 0), 1);
+    assert_eq!(get_node_time('Z', 0), 26);
+}
+
+#[test]
+fn parse_error_test() {
+    let err = Day7::parse("Step C must be finished before step A can begin.\nStep C must be finished before step f can begin.").unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (2, Some(37), "f"));
+
+    let err = Day7::parse("Step C must finish before step A can begin.").unwrap_err();
+    assert_eq!((err.line, err.column), (1, Some(1)));
+}
+
+#[test]
+fn cycle_error_test() {
+    let err = Day7::parse("Step A must be finished before step B can begin.\nStep B must be finished before step A can begin.").unwrap_err();
+    assert_eq!((err.line, err.column), (1, Some(1)));
+    assert_eq!(err.expected, "a step order with no loops, but step A already waits for step B");
+
+    let err = Day7::parse("Step C must be finished before step A can begin.\n\nStep A must be finished before step B can begin.\nStep D must be finished before step D can begin.").unwrap_err();
+    assert_eq!(err.line, 4);
+
+    let err = Day7::parse("Step A must be finished before step B can begin.\nStep B must be finished before step C can begin.\nStep D must be finished before step A can begin.\nStep C must be finished before step A can begin.").unwrap_err();
+    assert_eq!(err.line, 1);
+}
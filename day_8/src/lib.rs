@@ -0,0 +1,169 @@
+extern crate aoc_common;
+
+use aoc_common::{parse_field, ParseError, Solution};
+
+/// A node of the licence tree: its child nodes, then its metadata entries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    pub children: Vec<Node>,
+    pub metadata: Vec<u32>
+}
+
+impl Node {
+    pub fn metadata_sum(&self) -> u32 {
+        self.metadata.iter().sum::<u32>() + self.children.iter().map(|c| c.metadata_sum()).sum::<u32>()
+    }
+
+    /// The metadata sum for a leaf. Otherwise each metadata entry is a
+    /// 1-based index into the children, and entries with no child count 0.
+    pub fn value(&self) -> u32 {
+        if self.children.is_empty() {
+            return self.metadata.iter().sum();
+        }
+
+        let child_vals = self.children.iter().map(|c| c.value()).collect::<Vec<u32>>();
+        self.metadata.iter().fold(0, |acc, &m| {
+            if m == 0 || m > (child_vals.len() as u32) {
+                acc
+            } else {
+                acc + child_vals[(m-1) as usize]
+            }
+        })
+    }
+}
+
+/// A number from the input with where it was found, so a stream that
+/// doesn't make a tree can be reported at the node that breaks it.
+struct Field<'a> {
+    value: u32,
+    line_num: usize,
+    column: usize,
+    line: &'a str,
+    text: &'a str
+}
+
+impl<'a> Field<'a> {
+    fn error(&self, expected: &str) -> ParseError {
+        ParseError::in_line(self.line, self.text, expected).at_line(self.line_num)
+    }
+}
+
+struct Stream<'a> {
+    fields: Vec<Field<'a>>,
+    next: usize
+}
+
+impl<'a> Stream<'a> {
+    /// The next number, or an error just past the last one if the input has run out.
+    fn read(&mut self, expected: &str) -> Result<u32, ParseError> {
+        match self.fields.get(self.next) {
+            Some(f) => {
+                self.next += 1;
+                Ok(f.value)
+            },
+            None => Err(match self.fields.last() {
+                Some(last) => ParseError::in_line(last.line, &last.line[last.line.len()..], expected).at_line(last.line_num),
+                None => ParseError::new("", expected).at_line(1)
+            })
+        }
+    }
+
+    /// Reads a node and its children, with `header` describing the node
+    /// for an error if the input ends before it starts.
+    fn read_node(&mut self, header: &str) -> Result<Node, ParseError> {
+        let start = self.next;
+        let num_children = self.read(header)?;
+        let node = format!("the node at line {}, column {}", self.fields[start].line_num, self.fields[start].column);
+        let num_metadata = self.read(&format!("a metadata count for {}", node))?;
+
+        let children = (0..num_children)
+            .map(|n| self.read_node(&format!("child {} of {} of {}", n + 1, num_children, node)))
+            .collect::<Result<Vec<Node>, ParseError>>()?;
+
+        let metadata = (0..num_metadata)
+            .map(|n| self.read(&format!("metadata entry {} of {} of {}", n + 1, num_metadata, node)))
+            .collect::<Result<Vec<u32>, ParseError>>()?;
+
+        Ok(Node { children, metadata })
+    }
+}
+
+fn get_num_stream(input_str: &str) -> Result<Stream<'_>, ParseError> {
+    let mut fields = Vec::new();
+    for (i, l) in input_str.lines().enumerate() {
+        for x in l.split_whitespace() {
+            let value = parse_field(l, x, "a number").map_err(|e: ParseError| e.at_line(i + 1))?;
+            let column = l[..x.as_ptr() as usize - l.as_ptr() as usize].chars().count() + 1;
+            fields.push(Field { value, line_num: i + 1, column, line: l, text: x });
+        }
+    }
+
+    Ok(Stream { fields, next: 0 })
+}
+
+/// Builds the tree from the whole number stream, which must hold exactly
+/// one root node.
+fn parse_tree(input_str: &str) -> Result<Node, ParseError> {
+    let mut stream = get_num_stream(input_str)?;
+    let root = stream.read_node("a node header")?;
+
+    match stream.fields.get(stream.next) {
+        Some(extra) => Err(extra.error("the end of the input after the root node")),
+        None => Ok(root)
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Node;
+    type Config = ();
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input_str: &str) -> Result<Node, ParseError> {
+        parse_tree(input_str)
+    }
+
+    fn part1(root: &Node, _: &()) -> u32 {
+        root.metadata_sum()
+    }
+
+    fn part2(root: &Node, _: &()) -> u32 {
+        root.value()
+    }
+}
+
+#[test]
+fn part_1_test() {
+    assert_eq!(Day8::solve_part1(include_str!("../input/test_input_1.txt"), &()), Ok(138));
+}
+
+#[test]
+fn part_2_test() {
+    assert_eq!(Day8::solve_part2(include_str!("../input/test_input_1.txt"), &()), Ok(66));
+}
+
+#[test]
+fn parse_error_test() {
+    let err = Day8::parse("2 3 0 3 10 11 12 1 1 0 1 99 2 1 l 2").unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (1, Some(33), "l"));
+}
+
+#[test]
+fn parse_tree_error_test() {
+    let err = Day8::parse("1 1").unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (1, Some(4), ""));
+    assert_eq!(err.to_string(), "line 1, column 4: expected child 1 of 1 of the node at line 1, column 1, found \"\"");
+
+    let err = Day8::parse("2 3 0 3 10 11 12 1 1 0 1\n99 2").unwrap_err();
+    assert_eq!((err.line, err.column), (2, Some(5)));
+    assert_eq!(err.expected, "metadata entry 1 of 3 of the node at line 1, column 1");
+
+    let err = Day8::parse("0 1 5 0 1 5").unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (1, Some(7), "0"));
+    assert_eq!(err.expected, "the end of the input after the root node");
+
+    assert_eq!(Day8::parse("").unwrap_err().to_string(), "line 1: expected a node header, found \"\"");
+    assert_eq!(Day8::parse("0 0"), Ok(Node { children: Vec::new(), metadata: Vec::new() }));
+}
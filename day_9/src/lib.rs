@@ -0,0 +1,85 @@
+extern crate aoc_common;
+
+use std::collections::VecDeque;
+use aoc_common::{ParseError, Solution};
+
+fn rotate(circle: &mut VecDeque<u32>, rotator: isize) {
+    if rotator > 0 {
+        for _ in 0..rotator {
+            let rotate_val = circle.pop_front().unwrap();
+            circle.push_back(rotate_val);
+        }
+    } else {
+        for _ in 0..-rotator {
+            let rotate_val = circle.pop_back().unwrap();
+            circle.push_front(rotate_val);
+        }
+    }
+}
+
+fn get_high_score(num_players: u32, final_marble_score: u32) -> u64 {
+    let mut scores = vec!(0u64;num_players as usize);
+    let mut marbles = VecDeque::new();
+    marbles.push_back(0);
+
+    for t in 1..=final_marble_score {
+        let player = t % num_players;
+        if t % 23 == 0 {
+            rotate(&mut marbles, -7);
+            scores[player as usize] += t as u64 + marbles.pop_back().unwrap() as u64;
+            rotate(&mut marbles, 1);
+        } else {
+            rotate(&mut marbles, 1);
+            marbles.push_back(t);
+        }
+    }
+
+    *scores.iter().max().unwrap()
+}
+
+/// The largest last marble part 2 can play to, since it plays a hundred
+/// times as many marbles and each marble's number must fit in a `u32`.
+pub const MAX_LAST_MARBLE: u32 = u32::MAX / 100;
+
+pub struct Config {
+    pub players: u32,
+    pub last_marble: u32
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config { players: 464, last_marble: 71730 }
+    }
+}
+
+/// The game is fully described by its `Config`, so there's no input to parse.
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = ();
+    type Config = Config;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(_: &str) -> Result<(), ParseError> {
+        Ok(())
+    }
+
+    fn part1(_: &(), config: &Config) -> u64 {
+        get_high_score(config.players, config.last_marble)
+    }
+
+    fn part2(_: &(), config: &Config) -> u64 {
+        get_high_score(config.players, config.last_marble * 100)
+    }
+}
+
+#[test]
+fn part_1_test() {
+    assert_eq!(get_high_score(9, 25), 32);
+    assert_eq!(get_high_score(10, 1618), 8317);
+    assert_eq!(get_high_score(13, 7999), 146373);
+    assert_eq!(get_high_score(17, 1104), 2764);
+    assert_eq!(get_high_score(21, 6111), 54718);
+    assert_eq!(get_high_score(30, 5807), 37305);
+}
\ No newline at end of file